@@ -0,0 +1,28 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+use alloc::string::String;
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs};
+
+const ARG_NFT_CONTRACT_HASH: &str = "nft_contract_hash";
+const ARG_ENTRY_POINT: &str = "entry_point";
+const ARG_KEY_NAME: &str = "key_name";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let nft_contract_hash: ContractHash = runtime::get_named_arg::<Key>(ARG_NFT_CONTRACT_HASH)
+        .into_hash()
+        .map(|hash| ContractHash::new(hash))
+        .unwrap();
+    let key_name: String = runtime::get_named_arg(ARG_KEY_NAME);
+    let entry_point: String = runtime::get_named_arg(ARG_ENTRY_POINT);
+
+    let supply = runtime::call_contract::<u64>(nft_contract_hash, &entry_point, runtime_args! {});
+    runtime::put_key(&key_name, storage::new_uref(supply).into());
+}
@@ -0,0 +1,41 @@
+#![no_std]
+#![no_main]
+
+#[cfg(not(target_arch = "wasm32"))]
+compile_error!("target arch should be wasm32: compile with '--target wasm32-unknown-unknown'");
+
+extern crate alloc;
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs};
+
+const ENTRY_POINT_TOKENS_OF_OWNER: &str = "tokens_of_owner";
+const ARG_NFT_CONTRACT_HASH: &str = "nft_contract_hash";
+const ARG_OWNER: &str = "owner";
+const ARG_PAGE: &str = "page";
+const ARG_PAGE_SIZE: &str = "page_size";
+const ARG_KEY_NAME: &str = "key_name";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let nft_contract_hash: ContractHash = runtime::get_named_arg::<Key>(ARG_NFT_CONTRACT_HASH)
+        .into_hash()
+        .map(|hash| ContractHash::new(hash))
+        .unwrap();
+    let key_name: String = runtime::get_named_arg(ARG_KEY_NAME);
+    let owner: Key = runtime::get_named_arg(ARG_OWNER);
+    let page: u64 = runtime::get_named_arg(ARG_PAGE);
+    let page_size: u64 = runtime::get_named_arg(ARG_PAGE_SIZE);
+
+    let tokens_of_owner = runtime::call_contract::<(Vec<String>, Option<u64>)>(
+        nft_contract_hash,
+        ENTRY_POINT_TOKENS_OF_OWNER,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_PAGE => page,
+            ARG_PAGE_SIZE => page_size,
+        },
+    );
+    runtime::put_key(&key_name, storage::new_uref(tokens_of_owner).into());
+}
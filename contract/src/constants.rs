@@ -10,6 +10,7 @@ pub const ARG_ADDITIONAL_REQUIRED_METADATA: &str = "additional_required_metadata
 pub const ARG_ALLOW_MINTING: &str = "allow_minting";
 pub const ARG_APPROVE_ALL: &str = "approve_all";
 pub const ARG_BURN_MODE: &str = "burn_mode";
+pub const ARG_CHECKSUMS: &str = "checksums";
 pub const ARG_COLLECTION_NAME: &str = "collection_name";
 pub const ARG_COLLECTION_SYMBOL: &str = "collection_symbol";
 pub const ARG_CONTRACT_WHITELIST: &str = "contract_whitelist";
@@ -20,27 +21,38 @@ pub const ARG_IDENTIFIER_MODE: &str = "identifier_mode";
 pub const ARG_JSON_SCHEMA: &str = "json_schema";
 pub const ARG_OPERATOR: &str = "operator";
 pub const ARG_METADATA_MUTABILITY: &str = "metadata_mutability";
+pub const ARG_METADATA_NAME: &str = "metadata_name";
+pub const ARG_METADATA_SYMBOL: &str = "metadata_symbol";
 pub const ARG_MINTING_MODE: &str = "minting_mode";
 pub const ARG_NAMED_KEY_CONVENTION: &str = "named_key_convention";
 pub const ARG_NFT_KIND: &str = "nft_kind";
 pub const ARG_NFT_METADATA_KIND: &str = "nft_metadata_kind";
 pub const ARG_NFT_PACKAGE_KEY: &str = "cep78_package_key";
 pub const ARG_OPTIONAL_METADATA: &str = "optional_metadata";
+pub const ARG_OWNER: &str = "owner";
 pub const ARG_OWNERSHIP_MODE: &str = "ownership_mode";
 pub const ARG_OWNER_LOOKUP_MODE: &str = "owner_reverse_lookup_mode";
+pub const ARG_PAGE: &str = "page";
+pub const ARG_PAGE_SIZE: &str = "page_size";
 pub const ARG_RECEIPT_NAME: &str = "receipt_name";
 pub const ARG_SOURCE_KEY: &str = "source_key";
 pub const ARG_SPENDER: &str = "spender";
 pub const ARG_TARGET_KEY: &str = "target_key";
 pub const ARG_TOKEN_HASH: &str = "token_hash";
+pub const ARG_TOKEN_HASHES: &str = "token_hashes";
 pub const ARG_TOKEN_ID: &str = "token_id";
+pub const ARG_TOKEN_IDS: &str = "token_ids";
 pub const ARG_TOKEN_META_DATA: &str = "token_meta_data";
+pub const ARG_TOKEN_META_DATAS: &str = "token_meta_datas";
 pub const ARG_TOKEN_OWNER: &str = "token_owner";
+pub const ARG_TOKEN_OWNERS: &str = "token_owners";
 pub const ARG_TOTAL_TOKEN_SUPPLY: &str = "total_token_supply";
 pub const ARG_WHITELIST_MODE: &str = "whitelist_mode";
 
 pub const ENTRY_POINT_APPROVE: &str = "approve";
 pub const ENTRY_POINT_BALANCE_OF: &str = "balance_of";
+pub const ENTRY_POINT_BATCH_MINT: &str = "batch_mint";
+pub const ENTRY_POINT_BATCH_TRANSFER: &str = "batch_transfer";
 pub const ENTRY_POINT_BURN: &str = "burn";
 pub const ENTRY_POINT_GET_APPROVED: &str = "get_approved";
 pub const ENTRY_POINT_INIT: &str = "init";
@@ -52,9 +64,13 @@ pub const ENTRY_POINT_OWNER_OF: &str = "owner_of";
 pub const ENTRY_POINT_REVOKE: &str = "revoke";
 pub const ENTRY_POINT_REGISTER_OWNER: &str = "register_owner";
 pub const ENTRY_POINT_SET_APPROVALL_FOR_ALL: &str = "set_approval_for_all";
+pub const ENTRY_POINT_SET_METADATA_TEMPLATE: &str = "set_metadata_template";
 pub const ENTRY_POINT_SET_TOKEN_METADATA: &str = "set_token_metadata";
 pub const ENTRY_POINT_SET_VARIABLES: &str = "set_variables";
+pub const ENTRY_POINT_TOKENS_OF_OWNER: &str = "tokens_of_owner";
 pub const ENTRY_POINT_TRANSFER: &str = "transfer";
+pub const ENTRY_POINT_UPDATE_BASE_METADATA: &str = "update_base_metadata";
+pub const ENTRY_POINT_UPDATE_METADATA_URL_FOR_TOKENS: &str = "update_metadata_url_for_tokens";
 pub const ENTRY_POINT_UPDATED_RECEIPTS: &str = "updated_receipts";
 
 pub const ALLOW_MINTING: &str = "allow_minting";
@@ -76,14 +92,17 @@ pub const JSON_SCHEMA: &str = "json_schema";
 pub const METADATA_CEP78: &str = "metadata_cep78";
 pub const METADATA_CUSTOM_VALIDATED: &str = "metadata_custom_validated";
 pub const METADATA_MUTABILITY: &str = "metadata_mutability";
+pub const METADATA_NAME: &str = "metadata_name";
 pub const METADATA_NFT721: &str = "metadata_nft721";
 pub const METADATA_RAW: &str = "metadata_raw";
+pub const METADATA_SYMBOL: &str = "metadata_symbol";
 pub const MIGRATION_FLAG: &str = "migration_flag";
 pub const MINTING_MODE: &str = "minting_mode";
 pub const NFT_KIND: &str = "nft_kind";
 pub const NFT_METADATA_KIND: &str = "nft_metadata_kind";
 pub const NFT_METADATA_KINDS: &str = "nft_metadata_kinds";
 pub const NUMBER_OF_MINTED_TOKENS: &str = "number_of_minted_tokens";
+pub const NUMBER_OF_BURNT_TOKENS: &str = "number_of_burnt_tokens";
 pub const OPERATOR: &str = "operator";
 pub const OPERATORS: &str = "operators";
 pub const OWNED_TOKENS: &str = "owned_tokens";
@@ -178,6 +178,56 @@ pub enum NFTCoreError {
     InvalidContext = 173,
     MissingBaseMetadata = 174,
     InvalidBaseMetadata = 175,
+    NoPendingOwner = 176,
+    NotPendingOwner = 177,
+    MissingRole = 178,
+    InvalidRole = 179,
+    MissingRoleAccount = 180,
+    InvalidRoleAccount = 181,
+    MissingRequiredRole = 182,
+    TransferDisabled = 183,
+    MissingEnableTransfer = 184,
+    InvalidEnableTransfer = 185,
+    MissingTransferExemption = 186,
+    InvalidTransferExemption = 187,
+    MissingMetadataSeed = 188,
+    InvalidMetadataSeed = 189,
+    MissingMetadataOffset = 190,
+    InvalidMetadataOffset = 191,
+    MetadataOffsetAlreadySet = 192,
+    ShuffleNotEnabled = 193,
+    MissingProvenanceHash = 194,
+    InvalidProvenanceHash = 195,
+    MissingRevealBaseUri = 196,
+    InvalidRevealBaseUri = 197,
+    RevealNotEnabled = 198,
+    AlreadyRevealed = 199,
+    NotRevealed = 200,
+    MissingMetadataTemplate = 201,
+    InvalidMetadataTemplate = 202,
+    InvalidChecksums = 203,
+    InvalidTokenOwners = 204,
+    MissingTokenMetaDatas = 205,
+    InvalidTokenMetaDatas = 206,
+    InvalidBatchSize = 207,
+    MissingSupplyReserve = 208,
+    InvalidSupplyReserve = 209,
+    MissingSupplyBucket = 210,
+    InvalidSupplyBucket = 211,
+    BucketSupplyDepleted = 212,
+    MissingNumberOfBurntTokens = 213,
+    InvalidNumberOfBurntTokens = 214,
+    MissingPageCursor = 215,
+    InvalidPageCursor = 216,
+    MissingPageSize = 217,
+    InvalidPageSize = 218,
+    MissingRoyaltyRecipient = 219,
+    InvalidRoyaltyRecipient = 220,
+    MissingRoyaltyBasisPoints = 221,
+    InvalidRoyaltyBasisPoints = 222,
+    MissingSalePrice = 223,
+    InvalidSalePrice = 224,
+    BaseUriCommitted = 225,
 }
 
 impl From<NFTCoreError> for ApiError {
@@ -149,3 +149,137 @@ impl Migration {
         Self {}
     }
 }
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct OwnershipTransferProposed {
+    owner: Key,
+    pending_owner: Key,
+}
+
+impl OwnershipTransferProposed {
+    pub fn new(owner: Key, pending_owner: Key) -> Self {
+        Self {
+            owner,
+            pending_owner,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct OwnershipTransferCancelled {
+    owner: Key,
+    pending_owner: Key,
+}
+
+impl OwnershipTransferCancelled {
+    pub fn new(owner: Key, pending_owner: Key) -> Self {
+        Self {
+            owner,
+            pending_owner,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct OwnerTransferred {
+    previous_owner: Key,
+    new_owner: Key,
+}
+
+impl OwnerTransferred {
+    pub fn new(previous_owner: Key, new_owner: Key) -> Self {
+        Self {
+            previous_owner,
+            new_owner,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RoleGranted {
+    role: String,
+    account: Key,
+    sender: Key,
+}
+
+impl RoleGranted {
+    pub fn new(role: String, account: Key, sender: Key) -> Self {
+        Self {
+            role,
+            account,
+            sender,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RoleRevoked {
+    role: String,
+    account: Key,
+    sender: Key,
+}
+
+impl RoleRevoked {
+    pub fn new(role: String, account: Key, sender: Key) -> Self {
+        Self {
+            role,
+            account,
+            sender,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MetadataOffsetSet {
+    seed: String,
+    offset: u64,
+}
+
+impl MetadataOffsetSet {
+    pub fn new(seed: String, offset: u64) -> Self {
+        Self { seed, offset }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ProvenanceCommitted {
+    provenance_hash: Option<String>,
+    reveal_base_uri_hash: Option<String>,
+}
+
+impl ProvenanceCommitted {
+    pub fn new(provenance_hash: Option<String>, reveal_base_uri_hash: Option<String>) -> Self {
+        Self {
+            provenance_hash,
+            reveal_base_uri_hash,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MetadataRevealed {
+    base_uri: String,
+}
+
+impl MetadataRevealed {
+    pub fn new(base_uri: String) -> Self {
+        Self { base_uri }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct RoyaltyUpdated {
+    token_id: Option<String>,
+    recipient: Key,
+    basis_points: u32,
+}
+
+impl RoyaltyUpdated {
+    pub fn new(token_id: Option<String>, recipient: Key, basis_points: u32) -> Self {
+        Self {
+            token_id,
+            recipient,
+            basis_points,
+        }
+    }
+}
@@ -12,6 +12,10 @@ mod helpers;
 mod metadata;
 mod modalities;
 mod punk;
+mod reveal;
+mod roles;
+mod royalty;
+mod supply;
 mod utils;
 
 use alloc::{
@@ -24,14 +28,13 @@ use alloc::{
 };
 use constants::{ARG_ADDITIONAL_REQUIRED_METADATA, ARG_OPTIONAL_METADATA, NFT_METADATA_KINDS};
 use modalities::Requirement;
-use serde::{Deserialize, Serialize};
 
 use core::convert::{TryFrom, TryInto};
 
 use casper_types::{
-    contracts::NamedKeys, runtime_args, CLType, CLValue, ContractHash, ContractPackageHash,
-    EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, KeyTag, Parameter, RuntimeArgs,
-    Tagged,
+    contracts::NamedKeys, runtime_args, CLType, CLTyped, CLValue, ContractHash,
+    ContractPackageHash, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Key, KeyTag,
+    Parameter, RuntimeArgs, Tagged,
 };
 
 use casper_contract::{
@@ -287,6 +290,20 @@ pub extern "C" fn init() {
         "base_metadata_url",
         storage::new_uref(DEFAULT_BASE_METADATA.to_string()).into(),
     );
+    let metadata_name: String = utils::get_named_arg_with_user_errors(
+        ARG_METADATA_NAME,
+        NFTCoreError::MissingMetadataTemplate,
+        NFTCoreError::InvalidMetadataTemplate,
+    )
+    .unwrap_or_revert();
+    runtime::put_key(METADATA_NAME, storage::new_uref(metadata_name).into());
+    let metadata_symbol: String = utils::get_named_arg_with_user_errors(
+        ARG_METADATA_SYMBOL,
+        NFTCoreError::MissingMetadataTemplate,
+        NFTCoreError::InvalidMetadataTemplate,
+    )
+    .unwrap_or_revert();
+    runtime::put_key(METADATA_SYMBOL, storage::new_uref(metadata_symbol).into());
     runtime::put_key(
         COLLECTION_NAME,
         storage::new_uref(collection_name.clone()).into(),
@@ -359,6 +376,7 @@ pub extern "C" fn init() {
     // This is an internal variable that the installing account cannot change
     // but is incremented by the contract itself.
     runtime::put_key(NUMBER_OF_MINTED_TOKENS, storage::new_uref(0u64).into());
+    runtime::put_key(NUMBER_OF_BURNT_TOKENS, storage::new_uref(0u64).into());
 
     // Create the data dictionaries to store essential values, topically.
     storage::new_dictionary(TOKEN_OWNERS)
@@ -411,6 +429,56 @@ pub extern "C" fn init() {
     .unwrap_or_revert();
 
     punk::init(the_contract_owner);
+
+    let metadata_seed_hash: Option<String> = utils::get_named_arg_with_user_errors(
+        reveal::ARG_METADATA_SEED_HASH,
+        NFTCoreError::MissingMetadataSeed,
+        NFTCoreError::InvalidMetadataSeed,
+    )
+    .unwrap_or_revert();
+    reveal::init(metadata_seed_hash);
+
+    let provenance_hash: Option<String> = utils::get_named_arg_with_user_errors(
+        reveal::ARG_PROVENANCE_HASH,
+        NFTCoreError::MissingProvenanceHash,
+        NFTCoreError::InvalidProvenanceHash,
+    )
+    .unwrap_or_revert();
+    let reveal_base_uri_hash: Option<String> = utils::get_named_arg_with_user_errors(
+        reveal::ARG_REVEAL_BASE_URI_HASH,
+        NFTCoreError::MissingRevealBaseUri,
+        NFTCoreError::InvalidRevealBaseUri,
+    )
+    .unwrap_or_revert();
+    reveal::init_provenance(provenance_hash, reveal_base_uri_hash);
+
+    let team_reserve: u64 = utils::get_named_arg_with_user_errors(
+        supply::ARG_TEAM_RESERVE,
+        NFTCoreError::MissingSupplyReserve,
+        NFTCoreError::InvalidSupplyReserve,
+    )
+    .unwrap_or_revert();
+    let redeem_reserve: u64 = utils::get_named_arg_with_user_errors(
+        supply::ARG_REDEEM_RESERVE,
+        NFTCoreError::MissingSupplyReserve,
+        NFTCoreError::InvalidSupplyReserve,
+    )
+    .unwrap_or_revert();
+    supply::init(team_reserve, redeem_reserve, 0u64);
+
+    let royalty_recipient: Key = utils::get_named_arg_with_user_errors(
+        royalty::ARG_ROYALTY_RECIPIENT,
+        NFTCoreError::MissingRoyaltyRecipient,
+        NFTCoreError::InvalidRoyaltyRecipient,
+    )
+    .unwrap_or_revert();
+    let royalty_basis_points: u32 = utils::get_named_arg_with_user_errors(
+        royalty::ARG_ROYALTY_BASIS_POINTS,
+        NFTCoreError::MissingRoyaltyBasisPoints,
+        NFTCoreError::InvalidRoyaltyBasisPoints,
+    )
+    .unwrap_or_revert();
+    royalty::init(royalty_recipient, royalty_basis_points);
 }
 
 // set_variables allows the user to set any variable or any combination of variables simultaneously.
@@ -482,20 +550,55 @@ pub extern "C" fn set_variables() {
     }
 }
 
-#[derive(Serialize, Deserialize)]
-struct TokenMetadata {
-    name: String,
-    symbol: String,
-    token_uri: String,
-    checksum: String,
+// Mints `count` tokens to `token_owner`. Minting will fail if allow_minting is set to false.
+#[no_mangle]
+pub extern "C" fn mint() {
+    roles::only_role(roles::MINTER_ROLE);
+    when_minting_allowed();
+
+    let token_owner_key: Key = runtime::get_named_arg(ARG_TOKEN_OWNER);
+    let count: u64 = runtime::get_named_arg("count");
+    let checksums = metadata::get_checksums_arg(count as usize);
+
+    mint_tokens(
+        &vec![token_owner_key; count as usize],
+        vec![None; count as usize],
+        checksums,
+    );
 }
 
-// Mints a new token. Minting will fail if allow_minting is set to false.
+// Mints one token to each of `token_owners`, along with its metadata when supplied, and returns
+// the identifiers of the minted tokens.
 #[no_mangle]
-pub extern "C" fn mint() {
-    punk::only_owner_or_minter();
-    // punk::minting_valid_time();
-    // punk::take_cspr_from_minting();
+pub extern "C" fn batch_mint() {
+    roles::only_role(roles::MINTER_ROLE);
+    when_minting_allowed();
+
+    let token_owners: Vec<Key> = utils::get_named_arg_with_user_errors(
+        ARG_TOKEN_OWNERS,
+        NFTCoreError::MissingTokenOwners,
+        NFTCoreError::InvalidTokenOwners,
+    )
+    .unwrap_or_revert();
+    let token_meta_datas: Vec<Option<String>> = utils::get_named_arg_with_user_errors(
+        ARG_TOKEN_META_DATAS,
+        NFTCoreError::MissingTokenMetaDatas,
+        NFTCoreError::InvalidTokenMetaDatas,
+    )
+    .unwrap_or_revert();
+
+    if token_owners.is_empty() || token_owners.len() != token_meta_datas.len() {
+        runtime::revert(NFTCoreError::InvalidBatchSize);
+    }
+    let checksums = metadata::get_checksums_arg(token_owners.len());
+
+    let token_identifiers = mint_tokens(&token_owners, token_meta_datas, checksums);
+    let token_identifiers = CLValue::from_t(token_identifiers)
+        .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    runtime::ret(token_identifiers);
+}
+
+fn when_minting_allowed() {
     // The contract owner can toggle the minting behavior on and off over time.
     // The contract is toggled on by default.
     let minting_status = utils::get_stored_value_with_user_errors::<bool>(
@@ -509,24 +612,6 @@ pub extern "C" fn mint() {
         runtime::revert(NFTCoreError::MintingIsPaused);
     }
 
-    let total_token_supply = utils::get_stored_value_with_user_errors::<u64>(
-        TOTAL_TOKEN_SUPPLY,
-        NFTCoreError::MissingTotalTokenSupply,
-        NFTCoreError::InvalidTotalTokenSupply,
-    );
-
-    // The minted_tokens_count is the number of minted tokens so far.
-    let minted_tokens_count = utils::get_stored_value_with_user_errors::<u64>(
-        NUMBER_OF_MINTED_TOKENS,
-        NFTCoreError::MissingNumberOfMintedTokens,
-        NFTCoreError::InvalidNumberOfMintedTokens,
-    );
-
-    // Revert if the token supply has been exhausted.
-    if minted_tokens_count >= total_token_supply {
-        runtime::revert(NFTCoreError::TokenSupplyDepleted);
-    }
-
     let minting_mode: MintingMode = utils::get_stored_value_with_user_errors::<u8>(
         MINTING_MODE,
         NFTCoreError::MissingMintingMode,
@@ -569,12 +654,69 @@ pub extern "C" fn mint() {
             _ => runtime::revert(NFTCoreError::InvalidKey),
         }
     }
+}
+
+// Reverts unless the token owner key is allowed by the holder mode. When the contract whitelist is
+// locked, contracts can only hold tokens if they are whitelisted.
+fn when_valid_token_owner(
+    token_owner_key: &Key,
+    holder_mode: NFTHolderMode,
+    whitelist_mode: &WhitelistMode,
+    contract_whitelist: &[ContractHash],
+) {
+    match token_owner_key.tag() {
+        KeyTag::Account => {
+            if NFTHolderMode::Contracts == holder_mode {
+                runtime::revert(NFTCoreError::InvalidHolderMode)
+            }
+        }
+        KeyTag::Hash => {
+            if NFTHolderMode::Accounts == holder_mode {
+                runtime::revert(NFTCoreError::InvalidHolderMode)
+            }
+            let token_owner_contract = token_owner_key
+                .into_hash()
+                .map(ContractHash::new)
+                .unwrap_or_revert_with(NFTCoreError::InvalidKey);
+            if WhitelistMode::Locked == *whitelist_mode
+                && !contract_whitelist.contains(&token_owner_contract)
+            {
+                runtime::revert(NFTCoreError::UnlistedContractHash)
+            }
+        }
+        _ => runtime::revert(NFTCoreError::InvalidKey),
+    }
+}
+
+// Mints one token per owner. The supply is checked once for the whole batch, and tokens without
+// supplied metadata get it generated from the base metadata url and the metadata template.
+fn mint_tokens(
+    token_owners: &[Key],
+    token_meta_datas: Vec<Option<String>>,
+    checksums: Option<Vec<String>>,
+) -> Vec<TokenIdentifier> {
+    let count = token_owners.len() as u64;
+
+    let total_token_supply = utils::get_stored_value_with_user_errors::<u64>(
+        TOTAL_TOKEN_SUPPLY,
+        NFTCoreError::MissingTotalTokenSupply,
+        NFTCoreError::InvalidTotalTokenSupply,
+    );
+
+    // The minted_tokens_count is the number of minted tokens so far.
+    let minted_tokens_count = utils::get_stored_value_with_user_errors::<u64>(
+        NUMBER_OF_MINTED_TOKENS,
+        NFTCoreError::MissingNumberOfMintedTokens,
+        NFTCoreError::InvalidNumberOfMintedTokens,
+    );
+
+    // Revert if the batch doesn't fit in the remaining token supply.
+    if minted_tokens_count + count > total_token_supply {
+        runtime::revert(NFTCoreError::TokenSupplyDepleted);
+    }
+    supply::mint_from_bucket(helpers::get_immediate_caller_key(), count);
 
-    // The contract's ownership behavior (determined at installation) determines,
-    // who owns the NFT we are about to mint.()
     let caller = utils::get_verified_caller().unwrap_or_revert();
-    let token_owner_key: Key = runtime::get_named_arg(ARG_TOKEN_OWNER);
-    let count: u64 = runtime::get_named_arg("count");
 
     let events_mode: EventsMode =
         EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
@@ -584,30 +726,72 @@ pub extern "C" fn mint() {
         ))
         .unwrap_or_revert();
 
-    register_owner_internal(token_owner_key);
-    let owned_tokens_item_key = utils::encode_dictionary_item_key(token_owner_key);
     let base_metadata_url: String = utils::get_stored_value_with_user_errors::<String>(
         "base_metadata_url",
         NFTCoreError::MissingBaseMetadata,
         NFTCoreError::InvalidBaseMetadata,
     );
 
-    for i in 0..count {
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let holder_mode = utils::get_holder_mode().unwrap_or_revert();
+    let whitelist_mode: WhitelistMode = utils::get_stored_value_with_user_errors::<u8>(
+        WHITELIST_MODE,
+        NFTCoreError::MissingWhitelistMode,
+        NFTCoreError::InvalidWhitelistMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+    let contract_whitelist = utils::get_stored_value_with_user_errors::<Vec<ContractHash>>(
+        CONTRACT_WHITELIST,
+        NFTCoreError::MissingWhitelistMode,
+        NFTCoreError::InvalidWhitelistMode,
+    );
+    for token_owner_key in token_owners {
+        when_valid_token_owner(
+            token_owner_key,
+            holder_mode,
+            &whitelist_mode,
+            &contract_whitelist,
+        );
+    }
+
+    let mut minted_per_owner: BTreeMap<Key, u64> = BTreeMap::new();
+    let mut token_identifiers: Vec<TokenIdentifier> = Vec::new();
+
+    for (i, (token_owner_key, token_metadata)) in
+        token_owners.iter().zip(token_meta_datas).enumerate()
+    {
         // token id start from 1
-        let token_id = minted_tokens_count + i + 1;
-        let token_metadata = TokenMetadata {
-            name: "CasperPunks Gen1".to_string(),
-            symbol: "CP-GEN1".to_string(),
-            token_uri: base_metadata_url.clone() + &token_id.to_string(),
-            checksum: "".to_string(),
+        let token_id = minted_tokens_count + i as u64 + 1;
+        let token_metadata = match token_metadata {
+            Some(token_metadata) => token_metadata,
+            None => metadata::get_token_metadata_json(
+                base_metadata_url.clone() + &reveal::metadata_index(token_id).to_string(),
+                checksums
+                    .as_ref()
+                    .map(|checksums| checksums[i].clone())
+                    .unwrap_or_default(),
+            ),
+        };
+        // In Hash mode the token is identified by the hash of its metadata, which must be unique.
+        let token_identifier: TokenIdentifier = match identifier_mode {
+            NFTIdentifierMode::Ordinal => TokenIdentifier::Index(token_id),
+            NFTIdentifierMode::Hash => TokenIdentifier::Hash(base16::encode_lower(
+                &runtime::blake2b(token_metadata.as_bytes()),
+            )),
         };
-        let token_metadata = serde_json_wasm::to_string(&token_metadata).unwrap();
-        // This is the token ID.
-        let token_identifier: TokenIdentifier = TokenIdentifier::Index(token_id);
+        utils::insert_hash_id_lookups(token_id, token_identifier.clone());
         utils::upsert_dictionary_value_from_key(
             TOKEN_OWNERS,
             &token_identifier.get_dictionary_item_key(),
-            token_owner_key,
+            *token_owner_key,
         );
         utils::upsert_dictionary_value_from_key(
             TOKEN_ISSUERS,
@@ -615,50 +799,67 @@ pub extern "C" fn mint() {
             caller,
         );
 
-        utils::upsert_dictionary_value_from_key(
-            METADATA_CEP78,
+        metadata::write_validated_metadata(
             &token_identifier.get_dictionary_item_key(),
-            token_metadata.clone(),
+            &token_metadata,
         );
 
         match events_mode {
             EventsMode::NoEvents => {}
             EventsMode::CES => casper_event_standard::emit(Mint::new(
-                token_owner_key,
+                *token_owner_key,
                 token_identifier.clone(),
                 token_metadata.clone(),
             )),
             EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::Mint {
-                recipient: token_owner_key,
+                recipient: *token_owner_key,
                 token_id: token_identifier.clone(),
             }),
         }
+
+        if !minted_per_owner.contains_key(token_owner_key) {
+            register_owner_internal(*token_owner_key);
+        }
+        *minted_per_owner.entry(*token_owner_key).or_insert(0) += 1;
+        let owned_tokens_item_key = utils::encode_dictionary_item_key(*token_owner_key);
         utils::add_page_entry_and_page_record(token_id, &owned_tokens_item_key, true);
+
+        token_identifiers.push(token_identifier);
     }
+
     //Increment the count of owned tokens.
-    let updated_token_count =
-        match utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &owned_tokens_item_key) {
-            Some(balance) => balance + count,
-            None => count,
+    for (token_owner_key, minted) in minted_per_owner {
+        let owned_tokens_item_key = utils::encode_dictionary_item_key(token_owner_key);
+        let updated_token_count = match utils::get_dictionary_value_from_key::<u64>(
+            TOKEN_COUNT,
+            &owned_tokens_item_key,
+        ) {
+            Some(balance) => balance + minted,
+            None => minted,
         };
-    utils::upsert_dictionary_value_from_key(
-        TOKEN_COUNT,
-        &owned_tokens_item_key,
-        updated_token_count,
-    );
-    // Increment number_of_minted_tokens by one
+        utils::upsert_dictionary_value_from_key(
+            TOKEN_COUNT,
+            &owned_tokens_item_key,
+            updated_token_count,
+        );
+    }
+
+    // Increment number_of_minted_tokens by the size of the batch
     let number_of_minted_tokens_uref = utils::get_uref(
         NUMBER_OF_MINTED_TOKENS,
         NFTCoreError::MissingTotalTokenSupply,
         NFTCoreError::InvalidTotalTokenSupply,
     );
     storage::write(number_of_minted_tokens_uref, minted_tokens_count + count);
+
+    token_identifiers
 }
 
 // used for updating metadata url when ipfs is available
 #[no_mangle]
 pub extern "C" fn update_base_metadata() {
-    punk::only_owner_or_minter();
+    roles::only_role(roles::METADATA_EDITOR_ROLE);
+    reveal::when_base_uri_not_committed();
     let new_base: String = runtime::get_named_arg("base_metadata_url");
     let uref = utils::get_uref(
         "base_metadata_url",
@@ -668,9 +869,30 @@ pub extern "C" fn update_base_metadata() {
     storage::write(uref, new_base);
 }
 
+// name and symbol written into the metadata of newly minted or updated tokens
+#[no_mangle]
+pub extern "C" fn set_metadata_template() {
+    roles::only_role(roles::METADATA_EDITOR_ROLE);
+    let metadata_name: String = utils::get_named_arg_with_user_errors(
+        ARG_METADATA_NAME,
+        NFTCoreError::MissingMetadataTemplate,
+        NFTCoreError::InvalidMetadataTemplate,
+    )
+    .unwrap_or_revert();
+    let metadata_symbol: String = utils::get_named_arg_with_user_errors(
+        ARG_METADATA_SYMBOL,
+        NFTCoreError::MissingMetadataTemplate,
+        NFTCoreError::InvalidMetadataTemplate,
+    )
+    .unwrap_or_revert();
+    utils::set_key(METADATA_NAME, metadata_name);
+    utils::set_key(METADATA_SYMBOL, metadata_symbol);
+}
+
 #[no_mangle]
 pub extern "C" fn update_metadata_url_for_tokens() {
-    punk::only_owner_or_minter();
+    roles::only_role(roles::METADATA_EDITOR_ROLE);
+    reveal::when_revealed();
     let token_ids: Vec<u64> = runtime::get_named_arg("token_ids");
     let base_metadata_url: String = utils::get_stored_value_with_user_errors::<String>(
         "base_metadata_url",
@@ -678,19 +900,29 @@ pub extern "C" fn update_metadata_url_for_tokens() {
         NFTCoreError::InvalidBaseMetadata,
     );
 
-    for token_id in &token_ids {
-        let token_identifier: TokenIdentifier = TokenIdentifier::Index(*token_id);
-        let token_metadata = TokenMetadata {
-            name: "CasperPunks Generation 1".to_string(),
-            symbol: "CP-GEN-1".to_string(),
-            token_uri: base_metadata_url.clone() + &token_id.to_string(),
-            checksum: "".to_string(),
+    let checksums = metadata::get_checksums_arg(token_ids.len());
+
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    for (index, token_id) in token_ids.iter().enumerate() {
+        let token_identifier = utils::get_token_identifier_by_index(&identifier_mode, *token_id);
+        let checksum = match &checksums {
+            Some(checksums) => checksums[index].clone(),
+            None => metadata::get_stored_checksum(&token_identifier.get_dictionary_item_key()),
         };
-        let token_metadata: String = serde_json_wasm::to_string(&token_metadata).unwrap();
-        utils::upsert_dictionary_value_from_key(
-            METADATA_CEP78,
+        let token_metadata = metadata::get_token_metadata_json(
+            base_metadata_url.clone() + &reveal::metadata_index(*token_id).to_string(),
+            checksum,
+        );
+        metadata::write_validated_metadata(
             &token_identifier.get_dictionary_item_key(),
-            token_metadata.clone(),
+            &token_metadata,
         );
 
         // emit event to notify explorer
@@ -717,20 +949,28 @@ pub extern "C" fn burn() {
 
     let caller = utils::get_verified_caller().unwrap_or_revert();
 
-    // Revert if caller is not token_owner. This seems to be the only check we need to do.
     let token_owner = match utils::get_dictionary_value_from_key::<Key>(
         TOKEN_OWNERS,
         &token_identifier.get_dictionary_item_key(),
     ) {
-        Some(token_owner_key) => {
-            if token_owner_key != caller {
-                runtime::revert(NFTCoreError::InvalidTokenOwner)
-            }
-            token_owner_key
-        }
+        Some(token_owner_key) => token_owner_key,
         None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
     };
 
+    // Check if caller is an operator of the token owner, e.g. a redeem contract consuming it.
+    // A single token approval, such as a marketplace listing, doesn't allow burning.
+    let is_operator = token_owner != caller
+        && utils::get_dictionary_value_from_key::<bool>(
+            OPERATORS,
+            &utils::encode_key_and_value(&token_owner, &caller),
+        )
+        .unwrap_or_default();
+
+    // Revert if caller is not token_owner nor an operator.
+    if token_owner != caller && !is_operator {
+        runtime::revert(NFTCoreError::InvalidTokenOwner)
+    }
+
     // It makes sense to keep this token as owned by the caller. It just happens that the caller
     // owns a burnt token. That's all. Similarly, we should probably also not change the
     // owned_tokens dictionary.
@@ -765,6 +1005,13 @@ pub extern "C" fn burn() {
 
     utils::upsert_dictionary_value_from_key(TOKEN_COUNT, &owned_tokens_item_key, updated_balance);
 
+    let number_of_burnt_tokens = utils::get_stored_value_with_user_errors::<u64>(
+        NUMBER_OF_BURNT_TOKENS,
+        NFTCoreError::MissingNumberOfBurntTokens,
+        NFTCoreError::InvalidNumberOfBurntTokens,
+    );
+    utils::set_key(NUMBER_OF_BURNT_TOKENS, number_of_burnt_tokens + 1u64);
+
     // Emit Burn event.
     let events_mode: EventsMode =
         EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
@@ -862,6 +1109,9 @@ pub extern "C" fn approve() {
         )
         .unwrap_or_revert(),
     };
+
+    // While tokens are soulbound they can only be handed over to exempt spenders.
+    punk::when_transfer_enabled_for(spender);
     // If token owner or operator tries to approve itself that's probably a mistake and we revert.
     if caller == spender {
         runtime::revert(NFTCoreError::InvalidAccount);
@@ -1015,6 +1265,11 @@ pub extern "C" fn set_approval_for_all() {
         runtime::revert(NFTCoreError::InvalidAccount);
     }
 
+    // Revoking an operator is always allowed, approving one needs transfers to be enabled.
+    if approve_all {
+        punk::when_transfer_enabled_for(operator);
+    }
+
     // Depending on approve_all we either approve all or disapprove all.
     let owner_operator_item_key = utils::encode_key_and_value(&caller, &operator);
     utils::upsert_dictionary_value_from_key(OPERATORS, &owner_operator_item_key, approve_all);
@@ -1130,6 +1385,8 @@ pub extern "C" fn transfer() {
 
     let caller = utils::get_verified_caller().unwrap_or_revert();
 
+    punk::when_transfer_enabled_for(caller);
+
     // Check if caller is owner
     let is_owner = owner == caller;
 
@@ -1286,6 +1543,209 @@ pub extern "C" fn transfer() {
     }
 }
 
+// Transfers several tokens from the same source to the same target. Ownership and approvals are
+// checked for every token, while balances and pages are updated once per owner. Returns one
+// receipt per page of the source owner that was touched.
+#[no_mangle]
+pub extern "C" fn batch_transfer() {
+    // If we are in minter or assigned mode we are not allowed to transfer ownership of token, hence
+    // we revert.
+    if let OwnershipMode::Minter | OwnershipMode::Assigned =
+        utils::get_ownership_mode().unwrap_or_revert()
+    {
+        runtime::revert(NFTCoreError::InvalidOwnershipMode)
+    }
+
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let token_identifiers = utils::get_token_identifiers_from_runtime_args(&identifier_mode);
+    if token_identifiers.is_empty() {
+        runtime::revert(NFTCoreError::InvalidBatchSize);
+    }
+
+    let source_owner_key = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_SOURCE_KEY,
+        NFTCoreError::MissingAccountHash,
+        NFTCoreError::InvalidAccountHash,
+    )
+    .unwrap_or_revert();
+
+    let target_owner_key = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_TARGET_KEY,
+        NFTCoreError::MissingAccountHash,
+        NFTCoreError::InvalidAccountHash,
+    )
+    .unwrap_or_revert();
+
+    let caller = utils::get_verified_caller().unwrap_or_revert();
+
+    punk::when_transfer_enabled_for(caller);
+
+    // Check if caller is owner or operator once, approvals are checked per token.
+    let is_owner = source_owner_key == caller;
+    let is_operator = !is_owner
+        && utils::get_dictionary_value_from_key::<bool>(
+            OPERATORS,
+            &utils::encode_key_and_value(&source_owner_key, &caller),
+        )
+        .unwrap_or_default();
+
+    if NFTIdentifierMode::Hash == identifier_mode {
+        if utils::should_migrate_token_hashes(source_owner_key) {
+            utils::migrate_token_hashes(source_owner_key)
+        }
+
+        if utils::should_migrate_token_hashes(target_owner_key) {
+            utils::migrate_token_hashes(target_owner_key)
+        }
+    }
+
+    let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
+        EVENTS_MODE,
+        NFTCoreError::MissingEventsMode,
+        NFTCoreError::InvalidEventsMode,
+    ))
+    .unwrap_or_revert();
+
+    let mut token_dictionary_keys: Vec<String> = Vec::new();
+    for token_identifier in &token_identifiers {
+        let token_dictionary_key = token_identifier.get_dictionary_item_key();
+
+        // A token listed twice would be moved twice.
+        if token_dictionary_keys.contains(&token_dictionary_key) {
+            runtime::revert(NFTCoreError::InvalidTokenIdentifier);
+        }
+
+        // We assume we cannot transfer burnt tokens
+        if utils::is_token_burned(token_identifier) {
+            runtime::revert(NFTCoreError::PreviouslyBurntToken)
+        }
+
+        match utils::get_dictionary_value_from_key::<Key>(TOKEN_OWNERS, &token_dictionary_key) {
+            Some(owner) => {
+                if owner != source_owner_key {
+                    runtime::revert(NFTCoreError::InvalidAccount);
+                }
+            }
+            None => runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey),
+        }
+
+        // Check if caller is approved to execute transfer
+        let is_approved = !is_owner
+            && !is_operator
+            && match utils::get_dictionary_value_from_key::<Option<Key>>(
+                APPROVED,
+                &token_dictionary_key,
+            ) {
+                Some(Some(maybe_approved)) => caller == maybe_approved,
+                Some(None) | None => false,
+            };
+
+        // Revert if caller is not owner nor approved nor an operator.
+        if !is_owner && !is_approved && !is_operator {
+            runtime::revert(NFTCoreError::InvalidTokenOwner);
+        }
+
+        utils::upsert_dictionary_value_from_key(
+            TOKEN_OWNERS,
+            &token_dictionary_key,
+            target_owner_key,
+        );
+        utils::upsert_dictionary_value_from_key(
+            APPROVED,
+            &token_dictionary_key,
+            Option::<Key>::None,
+        );
+
+        match events_mode {
+            EventsMode::NoEvents => {}
+            EventsMode::CEP47 => record_cep47_event_dictionary(CEP47Event::Transfer {
+                sender: caller,
+                recipient: target_owner_key,
+                token_id: token_identifier.clone(),
+            }),
+            EventsMode::CES => {
+                // Emit Transfer event.
+                let spender = if is_owner { None } else { Some(caller) };
+                casper_event_standard::emit(Transfer::new(
+                    source_owner_key,
+                    spender,
+                    target_owner_key,
+                    token_identifier.clone(),
+                ));
+            }
+        }
+
+        token_dictionary_keys.push(token_dictionary_key);
+    }
+
+    let transferred = token_identifiers.len() as u64;
+    let source_owner_item_key = utils::encode_dictionary_item_key(source_owner_key);
+    let target_owner_item_key = utils::encode_dictionary_item_key(target_owner_key);
+
+    // Update the from_account balance
+    let updated_from_account_balance =
+        match utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &source_owner_item_key) {
+            Some(balance) if balance >= transferred => balance - transferred,
+            // This should never happen...
+            _ => runtime::revert(NFTCoreError::FatalTokenIdDuplication),
+        };
+    utils::upsert_dictionary_value_from_key(
+        TOKEN_COUNT,
+        &source_owner_item_key,
+        updated_from_account_balance,
+    );
+
+    // Update the to_account balance
+    let updated_to_account_balance =
+        match utils::get_dictionary_value_from_key::<u64>(TOKEN_COUNT, &target_owner_item_key) {
+            Some(balance) => balance + transferred,
+            None => transferred,
+        };
+    utils::upsert_dictionary_value_from_key(
+        TOKEN_COUNT,
+        &target_owner_item_key,
+        updated_to_account_balance,
+    );
+
+    let mut receipts: Vec<(String, Key)> = Vec::new();
+    let reporting_mode = utils::get_reporting_mode();
+
+    if let OwnerReverseLookupMode::Complete | OwnerReverseLookupMode::TransfersOnly = reporting_mode
+    {
+        let token_indices: Vec<u64> = token_identifiers
+            .iter()
+            .map(utils::get_token_index)
+            .collect();
+        if OwnerReverseLookupMode::TransfersOnly == reporting_mode {
+            for token_index in &token_indices {
+                utils::add_page_entry_and_page_record(*token_index, &source_owner_item_key, false);
+            }
+        }
+
+        for (page_table_entry, page_uref) in utils::update_page_entries_and_page_records(
+            &token_indices,
+            &source_owner_item_key,
+            &target_owner_item_key,
+        ) {
+            receipts.push((
+                utils::get_receipt_name(page_table_entry),
+                Key::dictionary(page_uref, source_owner_item_key.as_bytes()),
+            ));
+        }
+    }
+
+    let receipts =
+        CLValue::from_t(receipts).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    runtime::ret(receipts)
+}
+
 // Returns the length of the Vec<String> in OWNED_TOKENS dictionary. If key is not found
 // it returns 0.
 #[no_mangle]
@@ -1307,6 +1767,101 @@ pub extern "C" fn balance_of() {
     runtime::ret(balance_cl_value);
 }
 
+// Returns up to `page_size` tokens of the owner, starting at the token index given by `page`,
+// along with the index to continue from. The index is None once every page has been walked.
+// Tokens are returned as their token id or token hash, depending on the identifier mode, and burnt
+// tokens are skipped.
+#[no_mangle]
+pub extern "C" fn tokens_of_owner() {
+    if let OwnerReverseLookupMode::NoLookUp = utils::get_reporting_mode() {
+        runtime::revert(NFTCoreError::InvalidReportingMode)
+    }
+
+    let owner_key = utils::get_named_arg_with_user_errors::<Key>(
+        ARG_OWNER,
+        NFTCoreError::MissingTokenOwner,
+        NFTCoreError::InvalidTokenOwner,
+    )
+    .unwrap_or_revert();
+
+    let cursor = utils::get_named_arg_with_user_errors::<u64>(
+        ARG_PAGE,
+        NFTCoreError::MissingPageCursor,
+        NFTCoreError::InvalidPageCursor,
+    )
+    .unwrap_or_revert();
+
+    let page_size = utils::get_named_arg_with_user_errors::<u64>(
+        ARG_PAGE_SIZE,
+        NFTCoreError::MissingPageSize,
+        NFTCoreError::InvalidPageSize,
+    )
+    .unwrap_or_revert();
+    if page_size == 0 {
+        runtime::revert(NFTCoreError::InvalidPageSize)
+    }
+
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+
+    let owner_item_key = utils::encode_dictionary_item_key(owner_key);
+
+    // Owners that were never registered own no tokens.
+    let page_table = utils::get_dictionary_value_from_key::<Vec<bool>>(PAGE_TABLE, &owner_item_key)
+        .unwrap_or_default();
+
+    let mut token_identifiers: Vec<String> = Vec::new();
+    let mut next_cursor: Option<u64> = None;
+
+    let first_page_table_entry = cursor / utils::PAGE_SIZE;
+    'pages: for (page_table_entry, has_page) in page_table
+        .iter()
+        .enumerate()
+        .skip(first_page_table_entry as usize)
+    {
+        if !has_page {
+            continue;
+        }
+        let page_table_entry = page_table_entry as u64;
+        let page = utils::get_dictionary_value_from_key::<Vec<bool>>(
+            &format!("{PREFIX_PAGE_DICTIONARY}_{page_table_entry}"),
+            &owner_item_key,
+        )
+        .unwrap_or_revert_with(NFTCoreError::InvalidPageNumber);
+
+        let first_page_address = if page_table_entry == first_page_table_entry {
+            cursor % utils::PAGE_SIZE
+        } else {
+            0
+        };
+        for (page_address, is_owned) in page.iter().enumerate().skip(first_page_address as usize) {
+            if !is_owned {
+                continue;
+            }
+            let token_index = page_table_entry * utils::PAGE_SIZE + page_address as u64;
+            let token_identifier =
+                utils::get_token_identifier_by_index(&identifier_mode, token_index);
+            if utils::is_token_burned(&token_identifier) {
+                continue;
+            }
+            if token_identifiers.len() as u64 == page_size {
+                next_cursor = Some(token_index);
+                break 'pages;
+            }
+            token_identifiers.push(token_identifier.get_dictionary_item_key());
+        }
+    }
+
+    let tokens_of_owner = CLValue::from_t((token_identifiers, next_cursor))
+        .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    runtime::ret(tokens_of_owner);
+}
+
 // Returns the owner for a specified token identifier, throws error if token id is not valid
 #[no_mangle]
 pub extern "C" fn owner_of() {
@@ -1487,13 +2042,6 @@ pub extern "C" fn set_token_metadata() {
         runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey)
     }
 
-    let metadata_kinds: BTreeMap<NFTMetadataKind, Requirement> =
-        utils::get_stored_value_with_user_errors(
-            NFT_METADATA_KINDS,
-            NFTCoreError::MissingNFTMetadataKind,
-            NFTCoreError::InvalidNFTMetadataKind,
-        );
-
     let updated_token_metadata: String = utils::get_named_arg_with_user_errors(
         ARG_TOKEN_META_DATA,
         NFTCoreError::MissingTokenMetaData,
@@ -1501,27 +2049,10 @@ pub extern "C" fn set_token_metadata() {
     )
     .unwrap_or_revert();
 
-    for (metadata_kind, required) in metadata_kinds {
-        if required == Requirement::Unneeded {
-            continue;
-        }
-        let token_metadata_validation =
-            metadata::validate_metadata(&metadata_kind, updated_token_metadata.clone());
-        match token_metadata_validation {
-            Ok(validated_token_metadata) => {
-                utils::upsert_dictionary_value_from_key(
-                    &metadata::get_metadata_dictionary_name(&metadata_kind),
-                    &token_identifier.get_dictionary_item_key(),
-                    validated_token_metadata,
-                );
-            }
-            Err(err) => {
-                if required == Requirement::Required {
-                    runtime::revert(err);
-                }
-            }
-        }
-    }
+    metadata::write_validated_metadata(
+        &token_identifier.get_dictionary_item_key(),
+        &updated_token_metadata,
+    );
 
     let events_mode = EventsMode::try_from(utils::get_stored_value_with_user_errors::<u8>(
         EVENTS_MODE,
@@ -1704,6 +2235,64 @@ pub extern "C" fn migrate() {
         storage::new_dictionary(OPERATORS)
             .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
     }
+    // Contracts installed before the flag was enforced keep their tokens transferable.
+    if runtime::get_key(punk::TRANSFER_EXEMPTIONS).is_none() {
+        utils::set_key(punk::ENABLE_TRANSFER, true);
+        storage::new_dictionary(punk::TRANSFER_EXEMPTIONS)
+            .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    }
+    // Contracts installed before the metadata template existed keep the names used by mint.
+    if runtime::get_key(METADATA_NAME).is_none() {
+        runtime::put_key(
+            METADATA_NAME,
+            storage::new_uref(metadata::DEFAULT_METADATA_NAME.to_string()).into(),
+        );
+        runtime::put_key(
+            METADATA_SYMBOL,
+            storage::new_uref(metadata::DEFAULT_METADATA_SYMBOL.to_string()).into(),
+        );
+    }
+    // Contracts installed before shuffling existed keep sequential metadata.
+    if runtime::get_key(reveal::METADATA_OFFSET).is_none() {
+        reveal::init(None);
+    }
+    if runtime::get_key(reveal::REVEALED).is_none() {
+        reveal::init_provenance(None, None);
+    }
+    // Contracts installed before roles existed hand them out to the previous owner and minter.
+    if runtime::get_key(roles::ROLES).is_none() {
+        let minter = runtime::get_key(punk::THE_CONTRACT_MINTER).map(|_| {
+            utils::get_stored_value_with_user_errors::<Key>(
+                punk::THE_CONTRACT_MINTER,
+                NFTCoreError::MissingContractOwner,
+                NFTCoreError::InvalidContractOwner,
+            )
+        });
+        roles::init(punk::owner_internal(), minter);
+    }
+    // Contracts installed before supply buckets existed mint everything from the public sale.
+    if runtime::get_key(supply::BUCKET_CAPS).is_none() {
+        let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
+            NUMBER_OF_MINTED_TOKENS,
+            NFTCoreError::MissingNumberOfMintedTokens,
+            NFTCoreError::InvalidNumberOfMintedTokens,
+        );
+        supply::init(0u64, 0u64, number_of_minted_tokens);
+    }
+    // Contracts installed before the burnt tokens counter existed start it from the number of
+    // tokens burnt so far, passed in by the upgrader.
+    if runtime::get_key(NUMBER_OF_BURNT_TOKENS).is_none() {
+        let number_of_burnt_tokens: u64 = utils::get_optional_named_arg_with_user_errors(
+            supply::ARG_NUMBER_OF_BURNT_TOKENS,
+            NFTCoreError::InvalidNumberOfBurntTokens,
+        )
+        .unwrap_or(0u64);
+        supply::init_burnt_tokens_counter(number_of_burnt_tokens);
+    }
+    // Contracts installed before royalties existed pay no royalty until one is set.
+    if runtime::get_key(royalty::ROYALTY_RECIPIENT).is_none() {
+        royalty::init(punk::owner_internal(), 0u32);
+    }
 }
 
 #[no_mangle]
@@ -1899,6 +2488,22 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint mints one token to each of the given owners, using the supplied metadata
+    // or generating it when absent, and returns the identifiers of the minted tokens.
+    let batch_mint = EntryPoint::new(
+        ENTRY_POINT_BATCH_MINT,
+        vec![
+            Parameter::new(ARG_TOKEN_OWNERS, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(
+                ARG_TOKEN_META_DATAS,
+                CLType::List(Box::new(CLType::Option(Box::new(CLType::String)))),
+            ),
+        ],
+        CLType::List(Box::new(TokenIdentifier::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint burns the token with provided token_id argument, after which it is no
     // longer possible to transfer it.
     // Looks up the owner of the supplied token_id arg. If caller is not owner we revert with
@@ -1928,6 +2533,23 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint transfers several tokens from one owner to another, reverting as a whole if
+    // any of them can't be transferred. The tokens are read from token_ids or token_hashes
+    // depending on the identifier mode. It returns the receipts of the touched pages.
+    let batch_transfer = EntryPoint::new(
+        ENTRY_POINT_BATCH_TRANSFER,
+        vec![
+            Parameter::new(ARG_SOURCE_KEY, CLType::Key),
+            Parameter::new(ARG_TARGET_KEY, CLType::Key),
+        ],
+        CLType::List(Box::new(CLType::Tuple2([
+            Box::new(CLType::String),
+            Box::new(CLType::Key),
+        ]))),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint approves another token holder (an approved account) to transfer tokens. It
     // reverts if token_id is invalid, if caller is not the owner nor operator, if token has already
     // been burnt, or if caller tries to approve themselves as an approved account.
@@ -2005,6 +2627,23 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint returns a page of the tokens owned by the provided token holder, along with
+    // the cursor of the next page
+    let tokens_of_owner = EntryPoint::new(
+        ENTRY_POINT_TOKENS_OF_OWNER,
+        vec![
+            Parameter::new(ARG_OWNER, CLType::Key),
+            Parameter::new(ARG_PAGE, CLType::U64),
+            Parameter::new(ARG_PAGE_SIZE, CLType::U64),
+        ],
+        CLType::Tuple2([
+            Box::new(CLType::List(Box::new(CLType::String))),
+            Box::new(CLType::Option(Box::new(CLType::U64))),
+        ]),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint returns the metadata associated with the provided token_id
     let metadata = EntryPoint::new(
         ENTRY_POINT_METADATA,
@@ -2023,6 +2662,40 @@ fn generate_entry_points() -> EntryPoints {
         EntryPointType::Contract,
     );
 
+    // This entrypoint updates the base url of the token metadata.
+    let update_base_metadata = EntryPoint::new(
+        ENTRY_POINT_UPDATE_BASE_METADATA,
+        vec![Parameter::new("base_metadata_url", CLType::String)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint rewrites the metadata of the given tokens from the base url and the
+    // metadata template, along with optional checksums.
+    let update_metadata_url_for_tokens = EntryPoint::new(
+        ENTRY_POINT_UPDATE_METADATA_URL_FOR_TOKENS,
+        vec![Parameter::new(
+            "token_ids",
+            CLType::List(Box::new(CLType::U64)),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    // This entrypoint updates the name and symbol written into the token metadata.
+    let set_metadata_template = EntryPoint::new(
+        ENTRY_POINT_SET_METADATA_TEMPLATE,
+        vec![
+            Parameter::new(ARG_METADATA_NAME, CLType::String),
+            Parameter::new(ARG_METADATA_SYMBOL, CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     // This entrypoint will upgrade the contract from the 1_0 version to the
     // 1_1 version. The contract will insert any addition dictionaries and
     // sentinel values that were absent in the previous version of the contract.
@@ -2069,21 +2742,39 @@ fn generate_entry_points() -> EntryPoints {
     for e in &loot_box_entrypoints {
         entry_points.add_entry_point(e.clone());
     }
+    for e in roles::entry_points() {
+        entry_points.add_entry_point(e);
+    }
+    for e in reveal::entry_points() {
+        entry_points.add_entry_point(e);
+    }
+    for e in supply::entry_points() {
+        entry_points.add_entry_point(e);
+    }
+    for e in royalty::entry_points() {
+        entry_points.add_entry_point(e);
+    }
 
     entry_points.add_entry_point(init_contract);
     entry_points.add_entry_point(set_variables);
     entry_points.add_entry_point(mint);
+    entry_points.add_entry_point(batch_mint);
     entry_points.add_entry_point(burn);
     entry_points.add_entry_point(transfer);
+    entry_points.add_entry_point(batch_transfer);
     entry_points.add_entry_point(approve);
     entry_points.add_entry_point(revoke);
     entry_points.add_entry_point(owner_of);
     entry_points.add_entry_point(balance_of);
+    entry_points.add_entry_point(tokens_of_owner);
     entry_points.add_entry_point(get_approved);
     entry_points.add_entry_point(metadata);
     entry_points.add_entry_point(set_approval_for_all);
     entry_points.add_entry_point(is_approved_for_all);
     entry_points.add_entry_point(set_token_metadata);
+    entry_points.add_entry_point(update_base_metadata);
+    entry_points.add_entry_point(update_metadata_url_for_tokens);
+    entry_points.add_entry_point(set_metadata_template);
     entry_points.add_entry_point(migrate);
     entry_points.add_entry_point(updated_receipts);
     entry_points.add_entry_point(register_owner);
@@ -2330,6 +3021,64 @@ fn install_contract() {
     )
     .unwrap_or_revert();
 
+    let enable_transfer: bool = utils::get_optional_named_arg_with_user_errors(
+        punk::ENABLE_TRANSFER,
+        NFTCoreError::InvalidEnableTransfer,
+    )
+    .unwrap_or(false);
+
+    let metadata_seed_hash: Option<String> = utils::get_optional_named_arg_with_user_errors(
+        reveal::ARG_METADATA_SEED_HASH,
+        NFTCoreError::InvalidMetadataSeed,
+    );
+
+    let metadata_name: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_METADATA_NAME,
+        NFTCoreError::InvalidMetadataTemplate,
+    )
+    .unwrap_or_else(|| metadata::DEFAULT_METADATA_NAME.to_string());
+
+    let metadata_symbol: String = utils::get_optional_named_arg_with_user_errors(
+        ARG_METADATA_SYMBOL,
+        NFTCoreError::InvalidMetadataTemplate,
+    )
+    .unwrap_or_else(|| metadata::DEFAULT_METADATA_SYMBOL.to_string());
+
+    let provenance_hash: Option<String> = utils::get_optional_named_arg_with_user_errors(
+        reveal::ARG_PROVENANCE_HASH,
+        NFTCoreError::InvalidProvenanceHash,
+    );
+
+    let reveal_base_uri_hash: Option<String> = utils::get_optional_named_arg_with_user_errors(
+        reveal::ARG_REVEAL_BASE_URI_HASH,
+        NFTCoreError::InvalidRevealBaseUri,
+    );
+
+    let team_reserve: u64 = utils::get_optional_named_arg_with_user_errors(
+        supply::ARG_TEAM_RESERVE,
+        NFTCoreError::InvalidSupplyReserve,
+    )
+    .unwrap_or(0u64);
+
+    let redeem_reserve: u64 = utils::get_optional_named_arg_with_user_errors(
+        supply::ARG_REDEEM_RESERVE,
+        NFTCoreError::InvalidSupplyReserve,
+    )
+    .unwrap_or(0u64);
+
+    // Royalties go to the contract owner unless another recipient is given.
+    let royalty_recipient: Key = utils::get_optional_named_arg_with_user_errors(
+        royalty::ARG_ROYALTY_RECIPIENT,
+        NFTCoreError::InvalidRoyaltyRecipient,
+    )
+    .unwrap_or(the_contract_owner);
+
+    let royalty_basis_points: u32 = utils::get_optional_named_arg_with_user_errors(
+        royalty::ARG_ROYALTY_BASIS_POINTS,
+        NFTCoreError::InvalidRoyaltyBasisPoints,
+    )
+    .unwrap_or(0u32);
+
     // A sentinel string value which represents the entry for the addition
     // of a read only reference to the NFTs owned by the calling `Account` or `Contract`
     // This allows for users to look up a set of named keys and correctly identify
@@ -2364,6 +3113,16 @@ fn install_contract() {
             ARG_EVENTS_MODE => events_mode,
             punk::THE_CONTRACT_OWNER => the_contract_owner,
             punk::THE_CONTRACT_MINTER => contract_minter,
+            punk::ENABLE_TRANSFER => enable_transfer,
+            ARG_METADATA_NAME => metadata_name,
+            ARG_METADATA_SYMBOL => metadata_symbol,
+            reveal::ARG_METADATA_SEED_HASH => metadata_seed_hash,
+            reveal::ARG_PROVENANCE_HASH => provenance_hash,
+            reveal::ARG_REVEAL_BASE_URI_HASH => reveal_base_uri_hash,
+            supply::ARG_TEAM_RESERVE => team_reserve,
+            supply::ARG_REDEEM_RESERVE => redeem_reserve,
+            royalty::ARG_ROYALTY_RECIPIENT => royalty_recipient,
+            royalty::ARG_ROYALTY_BASIS_POINTS => royalty_basis_points,
         },
     );
 }
@@ -2430,6 +3189,15 @@ fn migrate_contract(access_key_name: String, package_key_name: String) {
             .unwrap_or_revert();
     }
 
+    if let Some(number_of_burnt_tokens) = utils::get_optional_named_arg_with_user_errors::<u64>(
+        supply::ARG_NUMBER_OF_BURNT_TOKENS,
+        NFTCoreError::InvalidNumberOfBurntTokens,
+    ) {
+        runtime_args
+            .insert(supply::ARG_NUMBER_OF_BURNT_TOKENS, number_of_burnt_tokens)
+            .unwrap_or_revert();
+    }
+
     runtime::call_contract::<()>(contract_hash, ENTRY_POINT_MIGRATE, runtime_args);
 }
 
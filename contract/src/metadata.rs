@@ -14,10 +14,14 @@ use casper_types::{
 };
 
 use crate::{
-    modalities::NFTMetadataKind, utils, NFTCoreError, ARG_JSON_SCHEMA, METADATA_CEP78,
-    METADATA_CUSTOM_VALIDATED, METADATA_NFT721, METADATA_RAW,
+    modalities::{NFTMetadataKind, Requirement},
+    utils, NFTCoreError, ARG_CHECKSUMS, ARG_JSON_SCHEMA, METADATA_CEP78, METADATA_CUSTOM_VALIDATED,
+    METADATA_NAME, METADATA_NFT721, METADATA_RAW, METADATA_SYMBOL, NFT_METADATA_KINDS,
 };
 
+pub(crate) const DEFAULT_METADATA_NAME: &str = "CasperPunks Gen1";
+pub(crate) const DEFAULT_METADATA_SYMBOL: &str = "CP-GEN1";
+
 // Metadata mutability is different from schema mutability.
 #[derive(Serialize, Deserialize, Clone)]
 pub(crate) struct MetadataSchemaProperty {
@@ -259,6 +263,37 @@ pub(crate) fn validate_metadata(
     }
 }
 
+// Validates the metadata against every configured kind and writes it to the matching
+// dictionaries. A required kind rejecting the metadata reverts, an optional one is skipped.
+pub(crate) fn write_validated_metadata(token_dictionary_key: &str, token_metadata: &str) {
+    let metadata_kinds: BTreeMap<NFTMetadataKind, Requirement> =
+        utils::get_stored_value_with_user_errors(
+            NFT_METADATA_KINDS,
+            NFTCoreError::MissingNFTMetadataKind,
+            NFTCoreError::InvalidNFTMetadataKind,
+        );
+
+    for (metadata_kind, required) in metadata_kinds {
+        if required == Requirement::Unneeded {
+            continue;
+        }
+        match validate_metadata(&metadata_kind, token_metadata.to_string()) {
+            Ok(validated_token_metadata) => {
+                utils::upsert_dictionary_value_from_key(
+                    &get_metadata_dictionary_name(&metadata_kind),
+                    token_dictionary_key,
+                    validated_token_metadata,
+                );
+            }
+            Err(err) => {
+                if required == Requirement::Required {
+                    runtime::revert(err);
+                }
+            }
+        }
+    }
+}
+
 pub(crate) fn get_metadata_dictionary_name(metadata_kind: &NFTMetadataKind) -> String {
     let name = match metadata_kind {
         NFTMetadataKind::CEP78 => METADATA_CEP78,
@@ -268,3 +303,51 @@ pub(crate) fn get_metadata_dictionary_name(metadata_kind: &NFTMetadataKind) -> S
     };
     name.to_string()
 }
+
+// Metadata written by `mint`, the name and symbol come from the stored template.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct TokenMetadata {
+    name: String,
+    symbol: String,
+    token_uri: String,
+    checksum: String,
+}
+
+pub(crate) fn get_token_metadata_json(token_uri: String, checksum: String) -> String {
+    let token_metadata = TokenMetadata {
+        name: utils::get_stored_value_with_user_errors::<String>(
+            METADATA_NAME,
+            NFTCoreError::MissingMetadataTemplate,
+            NFTCoreError::InvalidMetadataTemplate,
+        ),
+        symbol: utils::get_stored_value_with_user_errors::<String>(
+            METADATA_SYMBOL,
+            NFTCoreError::MissingMetadataTemplate,
+            NFTCoreError::InvalidMetadataTemplate,
+        ),
+        token_uri,
+        checksum,
+    };
+    serde_json_wasm::to_string(&token_metadata)
+        .unwrap_or_revert_with(NFTCoreError::FailedToJsonifyCEP99Metadata)
+}
+
+// Returns the checksum already recorded for a token, so rewriting its url keeps it.
+pub(crate) fn get_stored_checksum(token_identifier_dictionary_key: &str) -> String {
+    utils::get_dictionary_value_from_key::<String>(METADATA_CEP78, token_identifier_dictionary_key)
+        .and_then(|metadata| serde_json_wasm::from_str::<MetadataCEP78>(&metadata).ok())
+        .map(|metadata| metadata.checksum)
+        .unwrap_or_default()
+}
+
+// Reads the optional per-token checksums, which must match the number of tokens.
+pub(crate) fn get_checksums_arg(count: usize) -> Option<Vec<String>> {
+    let checksums = utils::get_optional_named_arg_with_user_errors::<Vec<String>>(
+        ARG_CHECKSUMS,
+        NFTCoreError::InvalidChecksums,
+    )?;
+    if checksums.len() != count {
+        runtime::revert(NFTCoreError::InvalidChecksums);
+    }
+    Some(checksums)
+}
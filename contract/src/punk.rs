@@ -1,13 +1,28 @@
-use crate::{error::NFTCoreError, helpers, utils};
+use crate::{
+    constants::NUMBER_OF_MINTED_TOKENS,
+    error::NFTCoreError,
+    events::events_ces::{OwnerTransferred, OwnershipTransferCancelled, OwnershipTransferProposed},
+    modalities::EventsMode,
+    roles, utils,
+};
 use alloc::{string::String, vec, vec::*};
 use casper_contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::{CLType, EntryPoint, EntryPointAccess, EntryPointType, Key};
+use casper_event_standard::EventInstance;
+use casper_types::{
+    bytesrepr::ToBytes, CLType, CLValue, EntryPoint, EntryPointAccess, EntryPointType, Key,
+    Parameter,
+};
 
 pub const THE_CONTRACT_OWNER: &str = "the_contract_owner";
 pub const THE_CONTRACT_MINTER: &str = "the_contract_minter";
+pub const THE_PENDING_OWNER: &str = "the_pending_owner";
+pub const ENABLE_TRANSFER: &str = "enable_transfer";
+pub const TRANSFER_EXEMPTIONS: &str = "transfer_exemptions";
+pub const ARG_ACCOUNT: &str = "account";
+pub const ARG_EXEMPT: &str = "exempt";
 
 pub fn only_owner() {
     utils::require(
@@ -16,15 +31,6 @@ pub fn only_owner() {
     );
 }
 
-pub fn only_owner_or_minter() {
-    let owner: Key = owner_internal();
-    let minter: Key = minter_internal();
-    let caller: Key = helpers::get_immediate_caller_key();
-    if caller != minter && caller != owner {
-        runtime::revert(NFTCoreError::OnlyOwner);
-    }
-}
-
 pub fn owner_internal() -> Key {
     let owner_key: Key = utils::get_stored_value_with_user_errors::<Key>(
         THE_CONTRACT_OWNER,
@@ -34,32 +40,135 @@ pub fn owner_internal() -> Key {
     owner_key
 }
 
-pub fn minter_internal() -> Key {
-    let minter_key: Key = utils::get_stored_value_with_user_errors::<Key>(
-        THE_CONTRACT_MINTER,
+// first step of an ownership transfer, the new owner has to accept it
+#[no_mangle]
+pub extern "C" fn propose_owner() {
+    only_owner();
+    let pending_owner: Key = utils::get_named_arg_with_user_errors(
+        THE_CONTRACT_OWNER,
         NFTCoreError::MissingContractOwner,
         NFTCoreError::InvalidContractOwner,
-    );
-    minter_key
+    )
+    .unwrap_or_revert();
+    utils::set_key(THE_PENDING_OWNER, Some(pending_owner));
+    emit_ces_event(OwnershipTransferProposed::new(
+        owner_internal(),
+        pending_owner,
+    ));
 }
 
 #[no_mangle]
-pub extern "C" fn transfer_owner() {
-    only_owner();
-    let contract_owner: Key = runtime::get_named_arg(THE_CONTRACT_OWNER);
-    utils::set_key(THE_CONTRACT_OWNER, contract_owner);
+pub extern "C" fn accept_ownership() {
+    let pending_owner =
+        pending_owner_internal().unwrap_or_revert_with(NFTCoreError::NoPendingOwner);
+    if pending_owner != utils::get_verified_caller().unwrap_or_revert() {
+        runtime::revert(NFTCoreError::NotPendingOwner);
+    }
+    let previous_owner = owner_internal();
+    utils::set_key(THE_CONTRACT_OWNER, pending_owner);
+    utils::set_key::<Option<Key>>(THE_PENDING_OWNER, None);
+    roles::transfer_ownership_roles(previous_owner, pending_owner);
+    emit_ces_event(OwnerTransferred::new(previous_owner, pending_owner));
 }
 
 #[no_mangle]
-pub extern "C" fn change_minter() {
+pub extern "C" fn cancel_ownership_transfer() {
     only_owner();
-    let new_minter: Key = utils::get_named_arg_with_user_errors(
-        THE_CONTRACT_MINTER,
+    let pending_owner =
+        pending_owner_internal().unwrap_or_revert_with(NFTCoreError::NoPendingOwner);
+    utils::set_key::<Option<Key>>(THE_PENDING_OWNER, None);
+    emit_ces_event(OwnershipTransferCancelled::new(
+        owner_internal(),
+        pending_owner,
+    ));
+}
+
+pub fn pending_owner_internal() -> Option<Key> {
+    runtime::get_key(THE_PENDING_OWNER)?;
+    utils::get_stored_value_with_user_errors::<Option<Key>>(
+        THE_PENDING_OWNER,
         NFTCoreError::MissingContractOwner,
         NFTCoreError::InvalidContractOwner,
     )
+}
+
+pub(crate) fn emit_ces_event<T: EventInstance + ToBytes>(event: T) {
+    if let EventsMode::CES = utils::get_events_mode() {
+        casper_event_standard::emit(event);
+    }
+}
+
+// tokens are soulbound until an admin enables transfers
+#[no_mangle]
+pub extern "C" fn set_transfer_enabled() {
+    roles::only_role(roles::ADMIN_ROLE);
+    let enable_transfer: bool = utils::get_named_arg_with_user_errors(
+        ENABLE_TRANSFER,
+        NFTCoreError::MissingEnableTransfer,
+        NFTCoreError::InvalidEnableTransfer,
+    )
     .unwrap_or_revert();
-    utils::set_key(THE_CONTRACT_MINTER, new_minter);
+    utils::set_key(ENABLE_TRANSFER, enable_transfer);
+}
+
+// exempt accounts and contracts can move tokens while transfers are disabled
+#[no_mangle]
+pub extern "C" fn set_transfer_exemption() {
+    roles::only_role(roles::ADMIN_ROLE);
+    let account: Key = utils::get_named_arg_with_user_errors(
+        ARG_ACCOUNT,
+        NFTCoreError::MissingTransferExemption,
+        NFTCoreError::InvalidTransferExemption,
+    )
+    .unwrap_or_revert();
+    let exempt: bool = utils::get_named_arg_with_user_errors(
+        ARG_EXEMPT,
+        NFTCoreError::MissingTransferExemption,
+        NFTCoreError::InvalidTransferExemption,
+    )
+    .unwrap_or_revert();
+    utils::upsert_dictionary_value_from_key(
+        TRANSFER_EXEMPTIONS,
+        &utils::encode_dictionary_item_key(account),
+        exempt,
+    );
+}
+
+pub fn transfer_enabled() -> bool {
+    utils::get_stored_value_with_user_errors::<bool>(
+        ENABLE_TRANSFER,
+        NFTCoreError::MissingEnableTransfer,
+        NFTCoreError::InvalidEnableTransfer,
+    )
+}
+
+pub fn is_transfer_exempt(account: Key) -> bool {
+    match account {
+        Key::Account(_) | Key::Hash(_) => utils::get_dictionary_value_from_key::<bool>(
+            TRANSFER_EXEMPTIONS,
+            &utils::encode_dictionary_item_key(account),
+        )
+        .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Reverts while transfers are disabled, unless `account` is exempt.
+pub fn when_transfer_enabled_for(account: Key) {
+    if !transfer_enabled() && !is_transfer_exempt(account) {
+        runtime::revert(NFTCoreError::TransferDisabled);
+    }
+}
+
+// lets the redeem contract work out the ids of the tokens it mints
+#[no_mangle]
+pub extern "C" fn number_of_minted_tokens() {
+    let number_of_minted_tokens = utils::get_stored_value_with_user_errors::<u64>(
+        NUMBER_OF_MINTED_TOKENS,
+        NFTCoreError::MissingNumberOfMintedTokens,
+        NFTCoreError::InvalidNumberOfMintedTokens,
+    );
+    runtime::ret(CLValue::from_t(number_of_minted_tokens).unwrap_or_revert());
 }
 
 pub fn init(contract_owner: Key) {
@@ -70,28 +179,64 @@ pub fn init(contract_owner: Key) {
         NFTCoreError::InvalidContractOwner,
     )
     .unwrap_or_revert();
-    runtime::put_key(
-        THE_CONTRACT_MINTER,
-        storage::new_uref(contract_minter).into(),
-    );
-    runtime::put_key("enable_transfer", storage::new_uref(false).into());
+    roles::init(contract_owner, Some(contract_minter));
+    let enable_transfer: bool = utils::get_named_arg_with_user_errors(
+        ENABLE_TRANSFER,
+        NFTCoreError::MissingEnableTransfer,
+        NFTCoreError::InvalidEnableTransfer,
+    )
+    .unwrap_or_revert();
+    runtime::put_key(ENABLE_TRANSFER, storage::new_uref(enable_transfer).into());
+    storage::new_dictionary(TRANSFER_EXEMPTIONS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
 }
 
 pub fn entry_points() -> Vec<EntryPoint> {
     vec![
         EntryPoint::new(
-            String::from("transfer_owner"),
+            String::from("propose_owner"),
+            vec![Parameter::new(THE_CONTRACT_OWNER, CLType::Key)],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+        EntryPoint::new(
+            String::from("accept_ownership"),
             vec![],
             CLType::Unit,
             EntryPointAccess::Public,
             EntryPointType::Contract,
         ),
         EntryPoint::new(
-            String::from("change_minter"),
+            String::from("cancel_ownership_transfer"),
             vec![],
             CLType::Unit,
             EntryPointAccess::Public,
             EntryPointType::Contract,
         ),
+        EntryPoint::new(
+            String::from("set_transfer_enabled"),
+            vec![Parameter::new(ENABLE_TRANSFER, CLType::Bool)],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+        EntryPoint::new(
+            String::from("set_transfer_exemption"),
+            vec![
+                Parameter::new(ARG_ACCOUNT, CLType::Key),
+                Parameter::new(ARG_EXEMPT, CLType::Bool),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+        EntryPoint::new(
+            String::from(NUMBER_OF_MINTED_TOKENS),
+            vec![],
+            CLType::U64,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
     ]
 }
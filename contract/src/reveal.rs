@@ -0,0 +1,227 @@
+//! Commit-reveal of the Gen1 metadata.
+//!
+//! The blake2b hash of a secret seed is committed at install time. Once minting is over an admin
+//! reveals the seed, which fixes an offset shifting every token onto another metadata index, so
+//! buyers cannot tell in advance which punk a token id maps to.
+//!
+//! The provenance hash of the artwork and the hash of the final base uri are committed at install
+//! time as well, proving the artwork order was fixed before minting started.
+use crate::{
+    constants::TOTAL_TOKEN_SUPPLY,
+    error::NFTCoreError,
+    events::events_ces::{MetadataOffsetSet, MetadataRevealed, ProvenanceCommitted},
+    punk, roles, utils,
+};
+use alloc::{string::String, vec, vec::*};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{CLType, EntryPoint, EntryPointAccess, EntryPointType, Parameter};
+
+pub const METADATA_SEED_HASH: &str = "metadata_seed_hash";
+pub const METADATA_OFFSET: &str = "metadata_offset";
+pub const ARG_METADATA_SEED_HASH: &str = "metadata_seed_hash";
+pub const ARG_METADATA_SEED: &str = "metadata_seed";
+pub const PROVENANCE_HASH: &str = "provenance_hash";
+pub const REVEAL_BASE_URI_HASH: &str = "reveal_base_uri_hash";
+pub const REVEALED: &str = "revealed";
+pub const ARG_PROVENANCE_HASH: &str = "provenance_hash";
+pub const ARG_REVEAL_BASE_URI_HASH: &str = "reveal_base_uri_hash";
+pub const ARG_BASE_URI: &str = "base_uri";
+const BASE_METADATA_URL: &str = "base_metadata_url";
+
+// domain separator, so the offset can't be derived from the committed hash
+const OFFSET_PREFIX: &[u8] = b"metadata_offset";
+
+fn seed_hash_internal() -> Option<String> {
+    utils::get_stored_value_with_user_errors::<Option<String>>(
+        METADATA_SEED_HASH,
+        NFTCoreError::MissingMetadataSeed,
+        NFTCoreError::InvalidMetadataSeed,
+    )
+}
+
+fn offset_internal() -> Option<u64> {
+    utils::get_stored_value_with_user_errors::<Option<u64>>(
+        METADATA_OFFSET,
+        NFTCoreError::MissingMetadataOffset,
+        NFTCoreError::InvalidMetadataOffset,
+    )
+}
+
+/// Returns the metadata index of `token_id`, which is the token id itself unless an offset has
+/// been set. Tokens minted before the offset keep their sequential metadata until
+/// `update_metadata_url_for_tokens` is run over them.
+pub fn metadata_index(token_id: u64) -> u64 {
+    match offset_internal() {
+        Some(offset) => {
+            let total_token_supply = utils::get_stored_value_with_user_errors::<u64>(
+                TOTAL_TOKEN_SUPPLY,
+                NFTCoreError::MissingTotalTokenSupply,
+                NFTCoreError::InvalidTotalTokenSupply,
+            );
+            // token ids start from 1, so the shuffled indices cover 1..=total_token_supply too
+            let token_position = token_id
+                .checked_sub(1)
+                .unwrap_or_revert_with(NFTCoreError::InvalidTokenIdentifier);
+            (token_position + offset) % total_token_supply + 1
+        }
+        None => token_id,
+    }
+}
+
+// reveals the seed committed at install time, can only succeed once. Tokens already minted
+// keep their sequential metadata until `update_metadata_url_for_tokens` rewrites them.
+#[no_mangle]
+pub extern "C" fn set_metadata_offset() {
+    roles::only_role(roles::ADMIN_ROLE);
+    let seed_hash = seed_hash_internal().unwrap_or_revert_with(NFTCoreError::ShuffleNotEnabled);
+    if offset_internal().is_some() {
+        runtime::revert(NFTCoreError::MetadataOffsetAlreadySet);
+    }
+    let seed: String = utils::get_named_arg_with_user_errors(
+        ARG_METADATA_SEED,
+        NFTCoreError::MissingMetadataSeed,
+        NFTCoreError::InvalidMetadataSeed,
+    )
+    .unwrap_or_revert();
+    if hex::encode(runtime::blake2b(seed.as_bytes())) != seed_hash {
+        runtime::revert(NFTCoreError::InvalidMetadataSeed);
+    }
+
+    let total_token_supply = utils::get_stored_value_with_user_errors::<u64>(
+        TOTAL_TOKEN_SUPPLY,
+        NFTCoreError::MissingTotalTokenSupply,
+        NFTCoreError::InvalidTotalTokenSupply,
+    );
+    let mut preimage = OFFSET_PREFIX.to_vec();
+    preimage.extend_from_slice(seed.as_bytes());
+    let digest = runtime::blake2b(preimage);
+    let mut offset_bytes = [0u8; 8];
+    offset_bytes.copy_from_slice(&digest[..8]);
+    let offset = u64::from_le_bytes(offset_bytes) % total_token_supply;
+
+    utils::set_key(METADATA_OFFSET, Some(offset));
+    punk::emit_ces_event(MetadataOffsetSet::new(seed, offset));
+}
+
+fn reveal_base_uri_hash_internal() -> Option<String> {
+    utils::get_stored_value_with_user_errors::<Option<String>>(
+        REVEAL_BASE_URI_HASH,
+        NFTCoreError::MissingRevealBaseUri,
+        NFTCoreError::InvalidRevealBaseUri,
+    )
+}
+
+fn revealed_internal() -> bool {
+    utils::get_stored_value_with_user_errors::<bool>(
+        REVEALED,
+        NFTCoreError::MissingRevealBaseUri,
+        NFTCoreError::InvalidRevealBaseUri,
+    )
+}
+
+/// Reverts while a base uri is committed but not yet revealed.
+pub fn when_revealed() {
+    if reveal_base_uri_hash_internal().is_some() && !revealed_internal() {
+        runtime::revert(NFTCoreError::NotRevealed);
+    }
+}
+
+/// Reverts once a base uri is committed, as only `reveal` may write it then.
+pub fn when_base_uri_not_committed() {
+    if reveal_base_uri_hash_internal().is_some() {
+        runtime::revert(NFTCoreError::BaseUriCommitted);
+    }
+}
+
+// writes the committed base uri, can only succeed once
+#[no_mangle]
+pub extern "C" fn reveal() {
+    roles::only_role(roles::METADATA_EDITOR_ROLE);
+    let base_uri_hash =
+        reveal_base_uri_hash_internal().unwrap_or_revert_with(NFTCoreError::RevealNotEnabled);
+    if revealed_internal() {
+        runtime::revert(NFTCoreError::AlreadyRevealed);
+    }
+    let base_uri: String = utils::get_named_arg_with_user_errors(
+        ARG_BASE_URI,
+        NFTCoreError::MissingRevealBaseUri,
+        NFTCoreError::InvalidRevealBaseUri,
+    )
+    .unwrap_or_revert();
+    if hex::encode(runtime::blake2b(base_uri.as_bytes())) != base_uri_hash {
+        runtime::revert(NFTCoreError::InvalidRevealBaseUri);
+    }
+
+    utils::set_key(BASE_METADATA_URL, base_uri.clone());
+    utils::set_key(REVEALED, true);
+    punk::emit_ces_event(MetadataRevealed::new(base_uri));
+}
+
+// must match the lowercase hex produced by `hex::encode`
+fn is_blake2b_hex(hash: &str) -> bool {
+    hash.len() == 64 && hash.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+}
+
+/// Stores the seed commitment. Without one the metadata index stays the token id.
+pub fn init(seed_hash: Option<String>) {
+    if let Some(seed_hash) = &seed_hash {
+        if !is_blake2b_hex(seed_hash) {
+            runtime::revert(NFTCoreError::InvalidMetadataSeed);
+        }
+    }
+    runtime::put_key(METADATA_SEED_HASH, storage::new_uref(seed_hash).into());
+    runtime::put_key(METADATA_OFFSET, storage::new_uref(None::<u64>).into());
+}
+
+/// Stores the provenance and base uri commitments. Without a base uri commitment `reveal` is
+/// disabled and the base metadata url can be updated at any time. With one, `reveal` is the only
+/// way to set it.
+pub fn init_provenance(provenance_hash: Option<String>, reveal_base_uri_hash: Option<String>) {
+    if let Some(provenance_hash) = &provenance_hash {
+        if !is_blake2b_hex(provenance_hash) {
+            runtime::revert(NFTCoreError::InvalidProvenanceHash);
+        }
+    }
+    if let Some(reveal_base_uri_hash) = &reveal_base_uri_hash {
+        if !is_blake2b_hex(reveal_base_uri_hash) {
+            runtime::revert(NFTCoreError::InvalidRevealBaseUri);
+        }
+    }
+    runtime::put_key(
+        PROVENANCE_HASH,
+        storage::new_uref(provenance_hash.clone()).into(),
+    );
+    runtime::put_key(
+        REVEAL_BASE_URI_HASH,
+        storage::new_uref(reveal_base_uri_hash.clone()).into(),
+    );
+    runtime::put_key(REVEALED, storage::new_uref(false).into());
+    if provenance_hash.is_some() || reveal_base_uri_hash.is_some() {
+        punk::emit_ces_event(ProvenanceCommitted::new(
+            provenance_hash,
+            reveal_base_uri_hash,
+        ));
+    }
+}
+
+pub fn entry_points() -> Vec<EntryPoint> {
+    vec![
+        EntryPoint::new(
+            String::from("set_metadata_offset"),
+            vec![Parameter::new(ARG_METADATA_SEED, CLType::String)],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+        EntryPoint::new(
+            String::from("reveal"),
+            vec![Parameter::new(ARG_BASE_URI, CLType::String)],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+    ]
+}
@@ -0,0 +1,211 @@
+//! Role based access control of the privileged entry points.
+//!
+//! Every role can be held by any number of keys. Contracts are identified by their package hash,
+//! so a role survives an upgrade of the contract holding it.
+use crate::{
+    constants::{ALLOW_MINTING, ARG_ALLOW_MINTING},
+    error::NFTCoreError,
+    events::events_ces::{RoleGranted, RoleRevoked},
+    helpers, punk, utils,
+};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::*,
+};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{CLType, CLValue, EntryPoint, EntryPointAccess, EntryPointType, Key, Parameter};
+
+pub const ROLES: &str = "roles";
+pub const ARG_ROLE: &str = "role";
+pub const ARG_ACCOUNT: &str = "account";
+
+/// Can grant and revoke every role.
+pub const ADMIN_ROLE: &str = "ADMIN";
+/// Can mint new tokens.
+pub const MINTER_ROLE: &str = "MINTER";
+/// Can update the base metadata url and the metadata of minted tokens.
+pub const METADATA_EDITOR_ROLE: &str = "METADATA_EDITOR";
+/// Can turn minting on and off.
+pub const PAUSER_ROLE: &str = "PAUSER";
+
+const ROLE_NAMES: [&str; 4] = [ADMIN_ROLE, MINTER_ROLE, METADATA_EDITOR_ROLE, PAUSER_ROLE];
+
+fn role_item_key(role: &str, account: &Key) -> String {
+    utils::encode_key_and_value(account, &role.to_string())
+}
+
+fn get_role_arg() -> String {
+    let role: String = utils::get_named_arg_with_user_errors(
+        ARG_ROLE,
+        NFTCoreError::MissingRole,
+        NFTCoreError::InvalidRole,
+    )
+    .unwrap_or_revert();
+    if !ROLE_NAMES.contains(&role.as_str()) {
+        runtime::revert(NFTCoreError::InvalidRole);
+    }
+    role
+}
+
+fn get_account_arg() -> Key {
+    utils::get_named_arg_with_user_errors(
+        ARG_ACCOUNT,
+        NFTCoreError::MissingRoleAccount,
+        NFTCoreError::InvalidRoleAccount,
+    )
+    .unwrap_or_revert()
+}
+
+pub fn has_role_internal(role: &str, account: &Key) -> bool {
+    utils::get_dictionary_value_from_key::<bool>(ROLES, &role_item_key(role, account))
+        .unwrap_or(false)
+}
+
+/// Reverts unless the immediate caller holds `role`.
+pub fn only_role(role: &str) {
+    let caller = helpers::get_immediate_caller_key();
+    if !has_role_internal(role, &caller) {
+        runtime::revert(NFTCoreError::MissingRequiredRole);
+    }
+}
+
+fn set_role(role: &str, account: &Key, granted: bool) {
+    utils::upsert_dictionary_value_from_key(ROLES, &role_item_key(role, account), granted);
+}
+
+fn grant_role_internal(role: &str, account: Key, sender: Key) {
+    if has_role_internal(role, &account) {
+        return;
+    }
+    set_role(role, &account, true);
+    punk::emit_ces_event(RoleGranted::new(role.to_string(), account, sender));
+}
+
+fn revoke_role_internal(role: &str, account: Key, sender: Key) {
+    if !has_role_internal(role, &account) {
+        return;
+    }
+    set_role(role, &account, false);
+    punk::emit_ces_event(RoleRevoked::new(role.to_string(), account, sender));
+}
+
+#[no_mangle]
+pub extern "C" fn grant_role() {
+    only_role(ADMIN_ROLE);
+    let role = get_role_arg();
+    let account = get_account_arg();
+    grant_role_internal(&role, account, helpers::get_immediate_caller_key());
+}
+
+#[no_mangle]
+pub extern "C" fn revoke_role() {
+    only_role(ADMIN_ROLE);
+    let role = get_role_arg();
+    let account = get_account_arg();
+    revoke_role_internal(&role, account, helpers::get_immediate_caller_key());
+}
+
+// lets the caller give up one of its own roles
+#[no_mangle]
+pub extern "C" fn renounce_role() {
+    let role = get_role_arg();
+    let caller = helpers::get_immediate_caller_key();
+    revoke_role_internal(&role, caller, caller);
+}
+
+#[no_mangle]
+pub extern "C" fn has_role() {
+    let role = get_role_arg();
+    let account = get_account_arg();
+    runtime::ret(CLValue::from_t(has_role_internal(&role, &account)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_allow_minting() {
+    only_role(PAUSER_ROLE);
+    let allow_minting: bool = utils::get_named_arg_with_user_errors(
+        ARG_ALLOW_MINTING,
+        NFTCoreError::MissingAllowMinting,
+        NFTCoreError::InvalidAllowMinting,
+    )
+    .unwrap_or_revert();
+    utils::set_key(ALLOW_MINTING, allow_minting);
+}
+
+/// Creates the roles dictionary. The admin starts with every role, so it keeps the powers the
+/// contract owner had before roles existed, and the minter is granted [`MINTER_ROLE`].
+pub fn init(admin: Key, minter: Option<Key>) {
+    storage::new_dictionary(ROLES).unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    for role in ROLE_NAMES {
+        grant_role_internal(role, admin, admin);
+    }
+    if let Some(minter) = minter {
+        grant_role_internal(MINTER_ROLE, minter, admin);
+    }
+}
+
+/// Hands the contract over on an ownership transfer. The new owner is granted [`ADMIN_ROLE`]
+/// and the previous owner loses every role it held, so the old key no longer controls the
+/// contract.
+pub fn transfer_ownership_roles(previous_owner: Key, new_owner: Key) {
+    grant_role_internal(ADMIN_ROLE, new_owner, new_owner);
+    if previous_owner == new_owner {
+        return;
+    }
+    for role in ROLE_NAMES {
+        revoke_role_internal(role, previous_owner, new_owner);
+    }
+}
+
+pub fn entry_points() -> Vec<EntryPoint> {
+    vec![
+        EntryPoint::new(
+            String::from("grant_role"),
+            vec![
+                Parameter::new(ARG_ROLE, CLType::String),
+                Parameter::new(ARG_ACCOUNT, CLType::Key),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+        EntryPoint::new(
+            String::from("revoke_role"),
+            vec![
+                Parameter::new(ARG_ROLE, CLType::String),
+                Parameter::new(ARG_ACCOUNT, CLType::Key),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+        EntryPoint::new(
+            String::from("renounce_role"),
+            vec![Parameter::new(ARG_ROLE, CLType::String)],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+        EntryPoint::new(
+            String::from("has_role"),
+            vec![
+                Parameter::new(ARG_ROLE, CLType::String),
+                Parameter::new(ARG_ACCOUNT, CLType::Key),
+            ],
+            CLType::Bool,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+        EntryPoint::new(
+            String::from("set_allow_minting"),
+            vec![Parameter::new(ARG_ALLOW_MINTING, CLType::Bool)],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+    ]
+}
@@ -0,0 +1,202 @@
+//! Creator royalties on secondary sales.
+//!
+//! A default royalty, a recipient and a share of the sale price in basis points, applies to every
+//! token. Single tokens can be given their own royalty, which takes precedence over the default.
+use crate::{
+    constants::{IDENTIFIER_MODE, TOKEN_OWNERS},
+    error::NFTCoreError,
+    events::events_ces::RoyaltyUpdated,
+    modalities::{NFTIdentifierMode, TokenIdentifier},
+    punk, roles, utils,
+};
+use alloc::{boxed::Box, string::String, vec, vec::*};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    CLType, CLValue, EntryPoint, EntryPointAccess, EntryPointType, Key, Parameter, U512,
+};
+use core::convert::TryInto;
+
+pub const ROYALTY_RECIPIENT: &str = "royalty_recipient";
+pub const ROYALTY_BASIS_POINTS: &str = "royalty_basis_points";
+pub const TOKEN_ROYALTIES: &str = "token_royalties";
+pub const ARG_ROYALTY_RECIPIENT: &str = "royalty_recipient";
+pub const ARG_ROYALTY_BASIS_POINTS: &str = "royalty_basis_points";
+pub const ARG_SALE_PRICE: &str = "sale_price";
+
+/// The basis points of the whole sale price.
+pub const MAX_BASIS_POINTS: u32 = 10_000;
+
+fn get_token_identifier_arg() -> TokenIdentifier {
+    let identifier_mode: NFTIdentifierMode = utils::get_stored_value_with_user_errors::<u8>(
+        IDENTIFIER_MODE,
+        NFTCoreError::MissingIdentifierMode,
+        NFTCoreError::InvalidIdentifierMode,
+    )
+    .try_into()
+    .unwrap_or_revert();
+    let token_identifier = utils::get_token_identifier_from_runtime_args(&identifier_mode);
+    // Only minted tokens have a royalty.
+    if utils::get_dictionary_value_from_key::<Key>(
+        TOKEN_OWNERS,
+        &token_identifier.get_dictionary_item_key(),
+    )
+    .is_none()
+    {
+        runtime::revert(NFTCoreError::MissingOwnerTokenIdentifierKey);
+    }
+    token_identifier
+}
+
+fn get_royalty_args() -> (Key, u32) {
+    let recipient: Key = utils::get_named_arg_with_user_errors(
+        ARG_ROYALTY_RECIPIENT,
+        NFTCoreError::MissingRoyaltyRecipient,
+        NFTCoreError::InvalidRoyaltyRecipient,
+    )
+    .unwrap_or_revert();
+    let basis_points: u32 = utils::get_named_arg_with_user_errors(
+        ARG_ROYALTY_BASIS_POINTS,
+        NFTCoreError::MissingRoyaltyBasisPoints,
+        NFTCoreError::InvalidRoyaltyBasisPoints,
+    )
+    .unwrap_or_revert();
+    if basis_points > MAX_BASIS_POINTS {
+        runtime::revert(NFTCoreError::InvalidRoyaltyBasisPoints);
+    }
+    (recipient, basis_points)
+}
+
+fn default_royalty_internal() -> (Key, u32) {
+    let recipient = utils::get_stored_value_with_user_errors::<Key>(
+        ROYALTY_RECIPIENT,
+        NFTCoreError::MissingRoyaltyRecipient,
+        NFTCoreError::InvalidRoyaltyRecipient,
+    );
+    let basis_points = utils::get_stored_value_with_user_errors::<u32>(
+        ROYALTY_BASIS_POINTS,
+        NFTCoreError::MissingRoyaltyBasisPoints,
+        NFTCoreError::InvalidRoyaltyBasisPoints,
+    );
+    (recipient, basis_points)
+}
+
+fn royalty_internal(token_identifier: &TokenIdentifier) -> (Key, u32) {
+    utils::get_dictionary_value_from_key::<Option<(Key, u32)>>(
+        TOKEN_ROYALTIES,
+        &token_identifier.get_dictionary_item_key(),
+    )
+    .flatten()
+    .unwrap_or_else(default_royalty_internal)
+}
+
+// sets the royalty of every token without a royalty of its own
+#[no_mangle]
+pub extern "C" fn set_royalty() {
+    roles::only_role(roles::ADMIN_ROLE);
+    let (recipient, basis_points) = get_royalty_args();
+    utils::set_key(ROYALTY_RECIPIENT, recipient);
+    utils::set_key(ROYALTY_BASIS_POINTS, basis_points);
+    punk::emit_ces_event(RoyaltyUpdated::new(None, recipient, basis_points));
+}
+
+// sets the royalty of a single token, read from token_id or token_hash depending on the
+// identifier mode
+#[no_mangle]
+pub extern "C" fn set_token_royalty() {
+    roles::only_role(roles::ADMIN_ROLE);
+    let token_identifier = get_token_identifier_arg();
+    let (recipient, basis_points) = get_royalty_args();
+    let token_id = token_identifier.get_dictionary_item_key();
+    utils::upsert_dictionary_value_from_key(
+        TOKEN_ROYALTIES,
+        &token_id,
+        Some((recipient, basis_points)),
+    );
+    punk::emit_ces_event(RoyaltyUpdated::new(Some(token_id), recipient, basis_points));
+}
+
+// makes a single token fall back to the default royalty, the token is read from token_id or
+// token_hash depending on the identifier mode
+#[no_mangle]
+pub extern "C" fn clear_token_royalty() {
+    roles::only_role(roles::ADMIN_ROLE);
+    let token_identifier = get_token_identifier_arg();
+    let token_id = token_identifier.get_dictionary_item_key();
+    utils::upsert_dictionary_value_from_key(TOKEN_ROYALTIES, &token_id, None::<(Key, u32)>);
+    let (recipient, basis_points) = default_royalty_internal();
+    punk::emit_ces_event(RoyaltyUpdated::new(Some(token_id), recipient, basis_points));
+}
+
+// returns the royalty recipient and the royalty amount owed on a sale of the token, read from
+// token_id or token_hash depending on the identifier mode
+#[no_mangle]
+pub extern "C" fn royalty_info() {
+    let token_identifier = get_token_identifier_arg();
+    let sale_price: U512 = utils::get_named_arg_with_user_errors(
+        ARG_SALE_PRICE,
+        NFTCoreError::MissingSalePrice,
+        NFTCoreError::InvalidSalePrice,
+    )
+    .unwrap_or_revert();
+    let (recipient, basis_points) = royalty_internal(&token_identifier);
+    let royalty_amount = sale_price
+        .checked_mul(U512::from(basis_points))
+        .unwrap_or_revert_with(NFTCoreError::InvalidSalePrice)
+        / U512::from(MAX_BASIS_POINTS);
+    let royalty_info = CLValue::from_t((recipient, royalty_amount))
+        .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    runtime::ret(royalty_info);
+}
+
+/// Stores the default royalty and creates the per token royalties dictionary.
+pub fn init(recipient: Key, basis_points: u32) {
+    if basis_points > MAX_BASIS_POINTS {
+        runtime::revert(NFTCoreError::InvalidRoyaltyBasisPoints);
+    }
+    runtime::put_key(ROYALTY_RECIPIENT, storage::new_uref(recipient).into());
+    runtime::put_key(ROYALTY_BASIS_POINTS, storage::new_uref(basis_points).into());
+    storage::new_dictionary(TOKEN_ROYALTIES)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+}
+
+pub fn entry_points() -> Vec<EntryPoint> {
+    vec![
+        EntryPoint::new(
+            String::from("set_royalty"),
+            vec![
+                Parameter::new(ARG_ROYALTY_RECIPIENT, CLType::Key),
+                Parameter::new(ARG_ROYALTY_BASIS_POINTS, CLType::U32),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+        EntryPoint::new(
+            String::from("set_token_royalty"),
+            vec![
+                Parameter::new(ARG_ROYALTY_RECIPIENT, CLType::Key),
+                Parameter::new(ARG_ROYALTY_BASIS_POINTS, CLType::U32),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+        EntryPoint::new(
+            String::from("clear_token_royalty"),
+            vec![], // <- either HASH or INDEX
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+        EntryPoint::new(
+            String::from("royalty_info"),
+            vec![Parameter::new(ARG_SALE_PRICE, CLType::U512)],
+            CLType::Tuple2([Box::new(CLType::Key), Box::new(CLType::U512)]),
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+    ]
+}
@@ -0,0 +1,249 @@
+//! Reserved allocations of the token supply.
+//!
+//! The total token supply is split into a team reserve, a redeem reserve for redeem-box and a
+//! public sale bucket holding the rest. Every bucket has its own cap and minted counter. A minter
+//! mints from the bucket it is bound to, or from the public sale bucket when it isn't bound.
+use crate::{
+    constants::{NUMBER_OF_BURNT_TOKENS, NUMBER_OF_MINTED_TOKENS, TOTAL_TOKEN_SUPPLY},
+    error::NFTCoreError,
+    roles, utils,
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+    vec::*,
+};
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{CLType, CLValue, EntryPoint, EntryPointAccess, EntryPointType, Key, Parameter};
+
+pub const BUCKET_CAPS: &str = "bucket_caps";
+pub const BUCKET_MINTED: &str = "bucket_minted";
+pub const MINTER_BUCKETS: &str = "minter_buckets";
+pub const ARG_TEAM_RESERVE: &str = "team_reserve";
+pub const ARG_REDEEM_RESERVE: &str = "redeem_reserve";
+pub const ARG_BUCKET: &str = "bucket";
+pub const ARG_NUMBER_OF_BURNT_TOKENS: &str = "number_of_burnt_tokens";
+pub const ARG_ACCOUNT: &str = "account";
+
+/// Tokens reserved for the team.
+pub const TEAM_BUCKET: &str = "team";
+/// Tokens left for the public sale.
+pub const PUBLIC_BUCKET: &str = "public";
+/// Tokens reserved for redeeming boxes.
+pub const REDEEM_BUCKET: &str = "redeem";
+
+const BUCKET_NAMES: [&str; 3] = [TEAM_BUCKET, PUBLIC_BUCKET, REDEEM_BUCKET];
+
+fn bucket_cap_internal(bucket: &str) -> u64 {
+    utils::get_dictionary_value_from_key::<u64>(BUCKET_CAPS, bucket)
+        .unwrap_or_revert_with(NFTCoreError::MissingSupplyBucket)
+}
+
+fn bucket_minted_internal(bucket: &str) -> u64 {
+    utils::get_dictionary_value_from_key::<u64>(BUCKET_MINTED, bucket)
+        .unwrap_or_revert_with(NFTCoreError::MissingSupplyBucket)
+}
+
+fn minter_bucket_internal(minter: Key) -> String {
+    utils::get_dictionary_value_from_key::<String>(
+        MINTER_BUCKETS,
+        &utils::encode_dictionary_item_key(minter),
+    )
+    .unwrap_or_else(|| PUBLIC_BUCKET.to_string())
+}
+
+/// Counts `count` tokens against the bucket of `minter`, reverting if the bucket can't hold them.
+pub fn mint_from_bucket(minter: Key, count: u64) {
+    let bucket = minter_bucket_internal(minter);
+    let minted = bucket_minted_internal(&bucket) + count;
+    if minted > bucket_cap_internal(&bucket) {
+        runtime::revert(NFTCoreError::BucketSupplyDepleted);
+    }
+    utils::upsert_dictionary_value_from_key(BUCKET_MINTED, &bucket, minted);
+}
+
+// binds a minter to the bucket it mints from
+#[no_mangle]
+pub extern "C" fn set_minter_bucket() {
+    roles::only_role(roles::ADMIN_ROLE);
+    let account: Key = utils::get_named_arg_with_user_errors(
+        ARG_ACCOUNT,
+        NFTCoreError::MissingRoleAccount,
+        NFTCoreError::InvalidRoleAccount,
+    )
+    .unwrap_or_revert();
+    let bucket: String = utils::get_named_arg_with_user_errors(
+        ARG_BUCKET,
+        NFTCoreError::MissingSupplyBucket,
+        NFTCoreError::InvalidSupplyBucket,
+    )
+    .unwrap_or_revert();
+    if !BUCKET_NAMES.contains(&bucket.as_str()) {
+        runtime::revert(NFTCoreError::InvalidSupplyBucket);
+    }
+    utils::upsert_dictionary_value_from_key(
+        MINTER_BUCKETS,
+        &utils::encode_dictionary_item_key(account),
+        bucket,
+    );
+}
+
+fn total_token_supply_internal() -> u64 {
+    utils::get_stored_value_with_user_errors::<u64>(
+        TOTAL_TOKEN_SUPPLY,
+        NFTCoreError::MissingTotalTokenSupply,
+        NFTCoreError::InvalidTotalTokenSupply,
+    )
+}
+
+fn number_of_minted_tokens_internal() -> u64 {
+    utils::get_stored_value_with_user_errors::<u64>(
+        NUMBER_OF_MINTED_TOKENS,
+        NFTCoreError::MissingNumberOfMintedTokens,
+        NFTCoreError::InvalidNumberOfMintedTokens,
+    )
+}
+
+fn number_of_burnt_tokens_internal() -> u64 {
+    utils::get_stored_value_with_user_errors::<u64>(
+        NUMBER_OF_BURNT_TOKENS,
+        NFTCoreError::MissingNumberOfBurntTokens,
+        NFTCoreError::InvalidNumberOfBurntTokens,
+    )
+}
+
+fn ret_u64(value: u64) {
+    runtime::ret(
+        CLValue::from_t(value).unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue),
+    );
+}
+
+// returns the live supply, the number of tokens that have been minted and not burnt
+#[no_mangle]
+pub extern "C" fn total_supply() {
+    ret_u64(number_of_minted_tokens_internal() - number_of_burnt_tokens_internal());
+}
+
+// alias of total_supply, for marketplaces asking for the circulating supply
+#[no_mangle]
+pub extern "C" fn circulating_supply() {
+    total_supply();
+}
+
+// returns the number of tokens that can still be minted
+#[no_mangle]
+pub extern "C" fn remaining_mintable() {
+    ret_u64(total_token_supply_internal() - number_of_minted_tokens_internal());
+}
+
+// returns the cap, the (bucket, cap, minted) of every bucket and the number of burnt tokens
+#[no_mangle]
+pub extern "C" fn supply_info() {
+    let total_token_supply = total_token_supply_internal();
+    let buckets: Vec<(String, u64, u64)> = BUCKET_NAMES
+        .iter()
+        .map(|bucket| {
+            (
+                bucket.to_string(),
+                bucket_cap_internal(bucket),
+                bucket_minted_internal(bucket),
+            )
+        })
+        .collect();
+    let number_of_burnt_tokens = number_of_burnt_tokens_internal();
+    let supply_info = CLValue::from_t((total_token_supply, buckets, number_of_burnt_tokens))
+        .unwrap_or_revert_with(NFTCoreError::FailedToConvertToCLValue);
+    runtime::ret(supply_info);
+}
+
+/// Creates the buckets. Whatever isn't reserved for the team or redeem-box goes to the public
+/// sale, and `public_minted` tokens are counted as already minted from it.
+pub fn init(team_reserve: u64, redeem_reserve: u64, public_minted: u64) {
+    let total_token_supply = total_token_supply_internal();
+    let public_sale = team_reserve
+        .checked_add(redeem_reserve)
+        .and_then(|reserved| total_token_supply.checked_sub(reserved))
+        .unwrap_or_revert_with(NFTCoreError::InvalidSupplyReserve);
+
+    storage::new_dictionary(BUCKET_CAPS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(BUCKET_MINTED)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    storage::new_dictionary(MINTER_BUCKETS)
+        .unwrap_or_revert_with(NFTCoreError::FailedToCreateDictionary);
+    for (bucket, cap, minted) in [
+        (TEAM_BUCKET, team_reserve, 0u64),
+        (PUBLIC_BUCKET, public_sale, public_minted),
+        (REDEEM_BUCKET, redeem_reserve, 0u64),
+    ] {
+        utils::upsert_dictionary_value_from_key(BUCKET_CAPS, bucket, cap);
+        utils::upsert_dictionary_value_from_key(BUCKET_MINTED, bucket, minted);
+    }
+}
+
+/// Creates the burnt tokens counter for contracts installed without it. Counting the burnt
+/// tokens on chain would read every minted token, so the count is passed in by the caller.
+pub fn init_burnt_tokens_counter(number_of_burnt_tokens: u64) {
+    if number_of_burnt_tokens > number_of_minted_tokens_internal() {
+        runtime::revert(NFTCoreError::InvalidNumberOfBurntTokens);
+    }
+    runtime::put_key(
+        NUMBER_OF_BURNT_TOKENS,
+        storage::new_uref(number_of_burnt_tokens).into(),
+    );
+}
+
+pub fn entry_points() -> Vec<EntryPoint> {
+    vec![
+        EntryPoint::new(
+            String::from("set_minter_bucket"),
+            vec![
+                Parameter::new(ARG_ACCOUNT, CLType::Key),
+                Parameter::new(ARG_BUCKET, CLType::String),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+        EntryPoint::new(
+            String::from("total_supply"),
+            vec![],
+            CLType::U64,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+        EntryPoint::new(
+            String::from("circulating_supply"),
+            vec![],
+            CLType::U64,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+        EntryPoint::new(
+            String::from("remaining_mintable"),
+            vec![],
+            CLType::U64,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+        EntryPoint::new(
+            String::from("supply_info"),
+            vec![],
+            CLType::Tuple3([
+                Box::new(CLType::U64),
+                Box::new(CLType::List(Box::new(CLType::Tuple3([
+                    Box::new(CLType::String),
+                    Box::new(CLType::U64),
+                    Box::new(CLType::U64),
+                ])))),
+                Box::new(CLType::U64),
+            ]),
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ),
+    ]
+}
@@ -1,5 +1,6 @@
 use alloc::{
     borrow::ToOwned,
+    collections::BTreeMap,
     format,
     string::{String, ToString},
     vec,
@@ -23,19 +24,22 @@ use casper_types::{
 
 use crate::{
     constants::{
-        ARG_TOKEN_HASH, ARG_TOKEN_ID, BURNT_TOKENS, BURN_MODE, HASH_BY_INDEX, HOLDER_MODE,
-        INDEX_BY_HASH, MIGRATION_FLAG, NUMBER_OF_MINTED_TOKENS, OWNED_TOKENS, OWNERSHIP_MODE,
-        PAGE_LIMIT, PAGE_TABLE, PREFIX_PAGE_DICTIONARY, RECEIPT_NAME, REPORTING_MODE, RLO_MFLAG,
-        TOKEN_OWNERS, UNMATCHED_HASH_COUNT,
+        ARG_TOKEN_HASH, ARG_TOKEN_HASHES, ARG_TOKEN_ID, ARG_TOKEN_IDS, BURNT_TOKENS, BURN_MODE,
+        EVENTS_MODE, HASH_BY_INDEX, HOLDER_MODE, INDEX_BY_HASH, MIGRATION_FLAG,
+        NUMBER_OF_MINTED_TOKENS, OWNED_TOKENS, OWNERSHIP_MODE, PAGE_LIMIT, PAGE_TABLE,
+        PREFIX_PAGE_DICTIONARY, RECEIPT_NAME, REPORTING_MODE, RLO_MFLAG, TOKEN_OWNERS,
+        UNMATCHED_HASH_COUNT,
     },
     error::NFTCoreError,
     events::events_ces::{
-        Approval, ApprovalForAll, ApprovalRevoked, Burn, MetadataUpdated, Migration, Mint,
+        Approval, ApprovalForAll, ApprovalRevoked, Burn, MetadataOffsetSet, MetadataRevealed,
+        MetadataUpdated, Migration, Mint, OwnerTransferred, OwnershipTransferCancelled,
+        OwnershipTransferProposed, ProvenanceCommitted, RoleGranted, RoleRevoked, RoyaltyUpdated,
         Transfer, VariablesSet,
     },
     modalities::{
-        BurnMode, MetadataRequirement, NFTHolderMode, NFTIdentifierMode, NFTMetadataKind,
-        OwnerReverseLookupMode, OwnershipMode, Requirement, TokenIdentifier,
+        BurnMode, EventsMode, MetadataRequirement, NFTHolderMode, NFTIdentifierMode,
+        NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode, Requirement, TokenIdentifier,
     },
     utils,
 };
@@ -338,6 +342,31 @@ pub fn get_token_identifier_from_runtime_args(
     }
 }
 
+pub fn get_token_identifiers_from_runtime_args(
+    identifier_mode: &NFTIdentifierMode,
+) -> Vec<TokenIdentifier> {
+    match identifier_mode {
+        NFTIdentifierMode::Ordinal => get_named_arg_with_user_errors::<Vec<u64>>(
+            ARG_TOKEN_IDS,
+            NFTCoreError::MissingTokenID,
+            NFTCoreError::InvalidTokenIdentifier,
+        )
+        .unwrap_or_revert()
+        .into_iter()
+        .map(TokenIdentifier::new_index)
+        .collect(),
+        NFTIdentifierMode::Hash => get_named_arg_with_user_errors::<Vec<String>>(
+            ARG_TOKEN_HASHES,
+            NFTCoreError::MissingTokenID,
+            NFTCoreError::InvalidTokenIdentifier,
+        )
+        .unwrap_or_revert()
+        .into_iter()
+        .map(TokenIdentifier::new_hash)
+        .collect(),
+    }
+}
+
 pub fn get_token_identifiers_from_dictionary(
     identifier_mode: &NFTIdentifierMode,
     owners_item_key: &str,
@@ -366,6 +395,16 @@ pub fn get_token_identifiers_from_dictionary(
     }
 }
 
+pub fn get_events_mode() -> EventsMode {
+    get_stored_value_with_user_errors::<u8>(
+        EVENTS_MODE,
+        NFTCoreError::MissingEventsMode,
+        NFTCoreError::InvalidEventsMode,
+    )
+    .try_into()
+    .unwrap_or_revert()
+}
+
 pub fn get_burn_mode() -> BurnMode {
     let burn_mode: BurnMode = get_stored_value_with_user_errors::<u8>(
         BURN_MODE,
@@ -454,6 +493,28 @@ pub fn insert_hash_id_lookups(
     );
 }
 
+// Inverse of `get_token_index`, looking up the hash of the token in Hash mode.
+pub fn get_token_identifier_by_index(
+    identifier_mode: &NFTIdentifierMode,
+    token_index: u64,
+) -> TokenIdentifier {
+    match identifier_mode {
+        NFTIdentifierMode::Ordinal => TokenIdentifier::Index(token_index),
+        NFTIdentifierMode::Hash => {
+            let hash_by_index_uref = get_uref(
+                HASH_BY_INDEX,
+                NFTCoreError::MissingHashByIndex,
+                NFTCoreError::InvalidHashByIndex,
+            );
+            let token_hash =
+                storage::dictionary_get::<String>(hash_by_index_uref, &token_index.to_string())
+                    .unwrap_or_revert()
+                    .unwrap_or_revert_with(NFTCoreError::InvalidTokenIdentifier);
+            TokenIdentifier::Hash(token_hash)
+        }
+    }
+}
+
 pub fn get_token_index(token_identifier: &TokenIdentifier) -> u64 {
     match token_identifier {
         TokenIdentifier::Index(token_index) => *token_index,
@@ -760,6 +821,75 @@ pub fn update_page_entry_and_page_record(
     (page_table_entry, page_uref)
 }
 
+// Moves several tokens between the same owners, reading and writing each page once. Returns the
+// touched page table entries along with their page urefs.
+pub fn update_page_entries_and_page_records(
+    token_indices: &[u64],
+    old_item_key: &str,
+    new_item_key: &str,
+) -> Vec<(u64, URef)> {
+    let mut page_addresses_by_entry: BTreeMap<u64, Vec<u64>> = BTreeMap::new();
+    for token_index in token_indices {
+        page_addresses_by_entry
+            .entry(token_index / PAGE_SIZE)
+            .or_default()
+            .push(token_index % PAGE_SIZE);
+    }
+
+    let page_table_uref = utils::get_uref(
+        PAGE_TABLE,
+        NFTCoreError::MissingPageTableURef,
+        NFTCoreError::InvalidPageTableURef,
+    );
+
+    let mut target_page_table = storage::dictionary_get::<Vec<bool>>(page_table_uref, new_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_revert_with(NFTCoreError::UnregisteredOwnerInTransfer);
+    let mut target_page_table_updated = false;
+
+    let mut pages = Vec::new();
+    for (page_table_entry, page_addresses) in page_addresses_by_entry {
+        let page_uref = utils::get_uref(
+            &format!("{PREFIX_PAGE_DICTIONARY}_{page_table_entry}"),
+            NFTCoreError::MissingStorageUref,
+            NFTCoreError::InvalidStorageUref,
+        );
+
+        let mut source_page = storage::dictionary_get::<Vec<bool>>(page_uref, old_item_key)
+            .unwrap_or_revert()
+            .unwrap_or_revert_with(NFTCoreError::InvalidPageNumber);
+        for page_address in &page_addresses {
+            if !source_page[*page_address as usize] {
+                runtime::revert(NFTCoreError::InvalidTokenIdentifier)
+            }
+            source_page[*page_address as usize] = false;
+        }
+        storage::dictionary_put(page_uref, old_item_key, source_page);
+
+        let mut target_page = if !target_page_table[page_table_entry as usize] {
+            // Create a new page here
+            target_page_table[page_table_entry as usize] = true;
+            target_page_table_updated = true;
+            vec![false; PAGE_SIZE as usize]
+        } else {
+            storage::dictionary_get::<Vec<bool>>(page_uref, new_item_key)
+                .unwrap_or_revert()
+                .unwrap_or_revert()
+        };
+        for page_address in &page_addresses {
+            target_page[*page_address as usize] = true;
+        }
+        storage::dictionary_put(page_uref, new_item_key, target_page);
+
+        pages.push((page_table_entry, page_uref));
+    }
+
+    if target_page_table_updated {
+        storage::dictionary_put(page_table_uref, new_item_key, target_page_table);
+    }
+    pages
+}
+
 pub fn create_metadata_requirements(
     base: NFTMetadataKind,
     req: Vec<u8>,
@@ -793,7 +923,16 @@ pub fn init_events() {
         .with::<Transfer>()
         .with::<MetadataUpdated>()
         .with::<VariablesSet>()
-        .with::<Migration>();
+        .with::<Migration>()
+        .with::<OwnershipTransferProposed>()
+        .with::<OwnershipTransferCancelled>()
+        .with::<OwnerTransferred>()
+        .with::<RoleGranted>()
+        .with::<RoleRevoked>()
+        .with::<MetadataOffsetSet>()
+        .with::<ProvenanceCommitted>()
+        .with::<MetadataRevealed>()
+        .with::<RoyaltyUpdated>();
     casper_event_standard::init(schemas);
 }
 
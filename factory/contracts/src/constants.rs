@@ -6,6 +6,8 @@ pub const NFT_FACTORY_CONTRACT_KEY_NAME: &str = "csp_factory_contract";
 pub const FEE_TOKEN_KEY_NAME: &str = "contract_hash";
 pub const CONTRACT_HASH_KEY_NAME: &str = "contract_hash";
 pub const CONTRACT_OWNER_KEY_NAME: &str = "contract_owner";
+pub const PENDING_OWNER_KEY_NAME: &str = "pending_owner";
+pub const PAUSED_KEY_NAME: &str = "paused";
 pub const REWARD_RATE_KEY_NAME: &str = "reward_rate";
 
 pub const LAST_UPDATE_KEY_NAME: &str = "last_update_time";
@@ -24,8 +26,12 @@ pub const USER_REWARD_PER_TOKEN_PAID_KEY_NAME: &str = "user_reward_per_token_pai
 
 // Entry points
 
-pub const TRANSFER_OWNER_ENTRY_POINT_NAME: &str = "transfer_owner";
+pub const PROPOSE_OWNER_ENTRY_POINT_NAME: &str = "propose_owner";
+pub const ACCEPT_OWNERSHIP_ENTRY_POINT_NAME: &str = "accept_ownership";
+pub const CANCEL_OWNERSHIP_TRANSFER_ENTRY_POINT_NAME: &str = "cancel_ownership_transfer";
 pub const INIT_ENTRY_POINT_NAME: &str = "init";
+pub const PAUSE_ENTRY_POINT_NAME: &str = "pause";
+pub const UNPAUSE_ENTRY_POINT_NAME: &str = "unpause";
 
 // Runtime argument names
 pub const ARG_CONTRACT_HASH: &str = "contract_hash";
@@ -194,20 +200,38 @@ pub const ARG_NFT_OWNER: &str = "nft_owner";
 pub const ARG_NFT_CONTRACT_PACKAGE: &str = "nft_contract_package";
 pub const ARG_NUMBER_OF_TICKETS: &str = "number_of_tickets";
 pub const ARG_IS_WHITELIST: &str = "is_whitelist";
+pub const ARG_WHITELIST_ONLY: &str = "whitelist_only";
+pub const IS_WHITELISTED_ENTRY_POINT_NAME: &str = "is_whitelisted";
 pub const NFT_MINTED_NUMBER: &str = "nft_minted_number";
-pub const NFT_MINTED_NUMBER_R3: &str = "nft_minted_number_r3";
+pub const ARG_PHASE_ID: &str = "phase_id";
+pub const ARG_MINTING_START_TIME: &str = "minting_start_time";
+pub const ARG_MINTING_END_TIME: &str = "minting_end_time";
+pub const ARG_TOTAL_BOX: &str = "total_box";
+pub const ARG_MAX_PER_ONE: &str = "max_per_one";
+pub const PHASES: &str = "phases";
+pub const NUMBER_OF_PHASES: &str = "number_of_phases";
+pub const PHASE_MINTED_BOX: &str = "phase_minted_box";
+pub const SET_PHASE_ENTRY_POINT_NAME: &str = "set_phase";
+pub const CURRENT_PHASE_ENTRY_POINT_NAME: &str = "current_phase";
+pub const ARG_MERKLE_ROOT: &str = "merkle_root";
+pub const ARG_PROOF: &str = "proof";
+pub const ARG_ALLOCATION: &str = "allocation";
+pub const MERKLE_ROOTS: &str = "merkle_roots";
+pub const SET_MERKLE_ROOT_ENTRY_POINT_NAME: &str = "set_merkle_root";
 pub const CONTRACT_PURSE: &str = "contract_purse";
+pub const ACCRUE_PROCEEDS: &str = "accrue_proceeds";
+pub const ARG_ACCRUE_PROCEEDS: &str = "accrue_proceeds";
+pub const SET_ACCRUE_PROCEEDS_ENTRY_POINT_NAME: &str = "set_accrue_proceeds";
+pub const WITHDRAW_ENTRY_POINT_NAME: &str = "withdraw";
+pub const ACCRUED_BALANCE_ENTRY_POINT_NAME: &str = "accrued_balance";
 pub const ARG_MINT_FEE: &str = "mint_fee";
-pub const ARG_MINT_FEE_R3: &str = "mint_fee_r3";
-pub const MINT_FEE: &str = "mint_fee";
-pub const MINT_FEE_R3: &str = "mint_fee_r3";
 pub const ARG_SRC_PURSE: &str = "src_purse";
 pub const ARG_WCSPR_CONTRACT: &str = "wcspr_contract";
 pub const WCSPR_CONTRACT: &str = "wcspr_contract";
 pub const ARG_FEE_RECEIVER: &str = "fee_receiver";
-pub const FEE_RECEIVER: &str = "fee_receiver";
+pub const FEE_RECEIVERS: &str = "fee_receivers";
+pub const ARG_FEE_RECEIVERS: &str = "fee_receivers";
+pub const SET_FEE_RECEIVERS_ENTRY_POINT_NAME: &str = "set_fee_receivers";
 pub const CHANGE_FEE_RECEIVER_ENTRY_POINT_NAME: &str = "change_fee_receiver";
 pub const CHANGE_WCSPR_CONTRACT_ENTRY_POINT_NAME: &str = "change_wcspr_contract";
-pub const CHANGE_MINT_FEE_ENTRY_POINT_NAME: &str = "change_mint_fee";
-pub const UPDATE_MINT_PARAMS_ENTRY_POINT_NAME: &str = "update_mint_params";
 pub const BOX_FACTORY_ACCESS: &str = "box_factory_access";
@@ -1,12 +1,14 @@
 use alloc::{boxed::Box, string::String, vec};
 
-use crate::constants::*;
+use crate::{constants::*, phase::Phase};
 
-use casper_types::{CLType, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter};
+use casper_types::{
+    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Parameter,
+};
 
-fn transfer_owner() -> EntryPoint {
+fn propose_owner() -> EntryPoint {
     EntryPoint::new(
-        String::from(TRANSFER_OWNER_ENTRY_POINT_NAME),
+        String::from(PROPOSE_OWNER_ENTRY_POINT_NAME),
         vec![Parameter::new(ARG_CONTRACT_OWNER, CLType::Key)],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -14,6 +16,26 @@ fn transfer_owner() -> EntryPoint {
     )
 }
 
+fn accept_ownership() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ACCEPT_OWNERSHIP_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn cancel_ownership_transfer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_OWNERSHIP_TRANSFER_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 fn change_fee_receiver() -> EntryPoint {
     EntryPoint::new(
         String::from(CHANGE_FEE_RECEIVER_ENTRY_POINT_NAME),
@@ -24,25 +46,55 @@ fn change_fee_receiver() -> EntryPoint {
     )
 }
 
-fn change_mint_fee() -> EntryPoint {
+fn set_fee_receivers() -> EntryPoint {
     EntryPoint::new(
-        String::from(CHANGE_MINT_FEE_ENTRY_POINT_NAME),
-        vec![Parameter::new(ARG_MINT_FEE, CLType::U256)],
+        String::from(SET_FEE_RECEIVERS_ENTRY_POINT_NAME),
+        vec![Parameter::new(
+            ARG_FEE_RECEIVERS,
+            CLType::List(Box::new(CLType::Tuple2([
+                Box::new(CLType::Key),
+                Box::new(CLType::U16),
+            ]))),
+        )],
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
     )
 }
-fn update_mint_params() -> EntryPoint {
+
+fn set_accrue_proceeds() -> EntryPoint {
     EntryPoint::new(
-        String::from(UPDATE_MINT_PARAMS_ENTRY_POINT_NAME),
-        vec![],
+        String::from(SET_ACCRUE_PROCEEDS_ENTRY_POINT_NAME),
+        vec![Parameter::new(ARG_ACCRUE_PROCEEDS, CLType::Bool)],
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
     )
 }
 
+fn withdraw() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WITHDRAW_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, CLType::U256),
+            Parameter::new(RECIPIENT_RUNTIME_ARG_NAME, CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn accrued_balance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ACCRUED_BALANCE_ENTRY_POINT_NAME),
+        vec![],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 fn set_addresses_whitelist() -> EntryPoint {
     EntryPoint::new(
         String::from(SET_ADDRESSES_WHITELIST),
@@ -53,7 +105,64 @@ fn set_addresses_whitelist() -> EntryPoint {
             ),
             Parameter::new(ARG_IS_WHITELIST, CLType::Bool),
         ],
-        CLType::String,
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn is_whitelisted() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_WHITELISTED_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS_RUNTIME_ARG_NAME, CLType::Key)],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn set_phase() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_PHASE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ARG_PHASE_ID, CLType::U64),
+            Parameter::new(ARG_MINTING_START_TIME, CLType::U64),
+            Parameter::new(ARG_MINTING_END_TIME, CLType::U64),
+            Parameter::new(ARG_MINT_FEE, CLType::U256),
+            Parameter::new(ARG_TOTAL_BOX, CLType::U64),
+            Parameter::new(ARG_MAX_PER_ONE, CLType::U8),
+            Parameter::new(ARG_WHITELIST_ONLY, CLType::Bool),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn set_merkle_root() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_MERKLE_ROOT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ARG_PHASE_ID, CLType::U64),
+            Parameter::new(
+                ARG_MERKLE_ROOT,
+                CLType::Option(Box::new(CLType::ByteArray(32))),
+            ),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn current_phase() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CURRENT_PHASE_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Option(Box::new(CLType::Tuple2([
+            Box::new(CLType::U64),
+            Box::new(Phase::cl_type()),
+        ]))),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     )
@@ -72,6 +181,26 @@ fn mint() -> EntryPoint {
     )
 }
 
+fn pause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PAUSE_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn unpause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNPAUSE_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 fn init() -> EntryPoint {
     EntryPoint::new(
         String::from(INIT_ENTRY_POINT_NAME),
@@ -86,12 +215,22 @@ fn init() -> EntryPoint {
 pub(crate) fn default() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
     // entry_points.add_entry_point(transfer_dev());
-    entry_points.add_entry_point(transfer_owner());
+    entry_points.add_entry_point(propose_owner());
+    entry_points.add_entry_point(accept_ownership());
+    entry_points.add_entry_point(cancel_ownership_transfer());
     entry_points.add_entry_point(change_fee_receiver());
-    entry_points.add_entry_point(change_mint_fee());
+    entry_points.add_entry_point(set_fee_receivers());
+    entry_points.add_entry_point(set_accrue_proceeds());
+    entry_points.add_entry_point(withdraw());
+    entry_points.add_entry_point(accrued_balance());
     entry_points.add_entry_point(mint());
     entry_points.add_entry_point(set_addresses_whitelist());
-    entry_points.add_entry_point(update_mint_params());
+    entry_points.add_entry_point(is_whitelisted());
+    entry_points.add_entry_point(set_phase());
+    entry_points.add_entry_point(set_merkle_root());
+    entry_points.add_entry_point(current_phase());
+    entry_points.add_entry_point(pause());
+    entry_points.add_entry_point(unpause());
     entry_points.add_entry_point(init());
     entry_points
 }
@@ -73,6 +73,33 @@ pub enum Error {
     InvalidMintingEnd = 443,
     MintingTimeInvalid = 444,
     OnlyOwner = 445,
+    NotWhitelisted = 446,
+    MissingPhaseId = 447,
+    InvalidPhaseId = 448,
+    MissingNumberOfPhases = 449,
+    InvalidNumberOfPhases = 450,
+    PhasesOverlap = 451,
+    MissingTotalBox = 452,
+    InvalidTotalBox = 453,
+    MissingMaxPerOne = 454,
+    InvalidMaxPerOne = 455,
+    MissingWhitelistOnly = 456,
+    InvalidWhitelistOnly = 457,
+    MissingMerkleRoot = 458,
+    InvalidMerkleRoot = 459,
+    InvalidMerkleProof = 460,
+    MissingAllocation = 461,
+    InvalidAllocation = 462,
+    InvalidFeeShares = 463,
+    MissingAccrueProceeds = 464,
+    InvalidAccrueProceeds = 465,
+    MissingRecipient = 466,
+    InvalidRecipient = 467,
+    InsufficientContractBalance = 468,
+    ContractPaused = 469,
+    NoPendingOwner = 470,
+    NotPendingOwner = 471,
+    PhaseBoxLimitExceeded = 472,
 }
 
 impl From<Error> for ApiError {
@@ -1,126 +1,179 @@
-#![allow(unused_parens)]
-#![allow(non_snake_case)]
-#![allow(dead_code)]
-
-// use std::collections::BTreeMap;
-extern crate alloc;
-use alloc::{
-    collections::BTreeMap,
-    string::{String, ToString},
-    vec::*,
-};
-use casper_contract::contract_api::storage;
-use casper_types::{account::AccountHash, ContractPackageHash, Key, URef, U256};
-
-use crate::helpers::get_key;
-
-pub enum FactoryEvent {
-    Approval {
-        owner: Key,
-        spender: Key,
-        value: U256,
-    },
-    Transfer {
-        from: Key,
-        to: Key,
-        value: U256,
-    },
-    MintFactory {
-        owner: Key,
-        minted: u8,
-    },
-    Withdrawal {
-        cspr_recipient: AccountHash,
-        from: Key,
-        value: U256,
-    },
-}
-
-impl FactoryEvent {
-    pub fn type_name(&self) -> String {
-        match self {
-            FactoryEvent::Approval {
-                owner: _,
-                spender: _,
-                value: _,
-            } => "approve",
-            FactoryEvent::Transfer {
-                from: _,
-                to: _,
-                value: _,
-            } => "transfer",
-            FactoryEvent::MintFactory {
-                owner: _,
-                minted: _,
-            } => "mint_factory",
-            FactoryEvent::Withdrawal {
-                cspr_recipient: _,
-                from: _,
-                value: _,
-            } => "withdrawal",
+use alloc::vec::Vec;
+
+use casper_event_standard::{Event, Schemas};
+use casper_types::{Key, U256};
+
+use crate::phase::Phase;
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct BoxMinted {
+    owner: Key,
+    phase_id: u64,
+    count: u8,
+    minted: u8,
+}
+
+impl BoxMinted {
+    pub fn new(owner: Key, phase_id: u64, count: u8, minted: u8) -> Self {
+        Self {
+            owner,
+            phase_id,
+            count,
+            minted,
         }
-        .to_string()
     }
 }
 
-pub fn contract_package_hash() -> ContractPackageHash {
-    get_key::<ContractPackageHash>("contract_package_hash").unwrap()
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MintPayment {
+    buyer: Key,
+    paid: U256,
+    required: U256,
+    refunded: U256,
 }
 
-pub(crate) fn emit(pair_event: &FactoryEvent) {
-    let mut events = Vec::new();
-    let package = contract_package_hash();
-    match pair_event {
-        FactoryEvent::Approval {
-            owner,
-            spender,
-            value,
-        } => {
-            let mut event = BTreeMap::new();
-            event.insert("contract_package_hash", package.to_string());
-            event.insert("event_type", pair_event.type_name());
-            event.insert("owner", owner.to_string());
-            event.insert("spender", spender.to_string());
-            event.insert("value", value.to_string());
-            events.push(event);
+impl MintPayment {
+    pub fn new(buyer: Key, paid: U256, required: U256, refunded: U256) -> Self {
+        Self {
+            buyer,
+            paid,
+            required,
+            refunded,
         }
-        FactoryEvent::Transfer { from, to, value } => {
-            let mut event = BTreeMap::new();
-            event.insert("contract_package_hash", package.to_string());
-            event.insert("event_type", pair_event.type_name());
-            event.insert("from", from.to_string());
-            event.insert("to", to.to_string());
-            event.insert("value", value.to_string());
-            events.push(event);
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct FeeChanged {
+    fee_receivers: Vec<(Key, u16)>,
+}
+
+impl FeeChanged {
+    pub fn new(fee_receivers: Vec<(Key, u16)>) -> Self {
+        Self { fee_receivers }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct PhaseChanged {
+    phase_id: u64,
+    start_time: u64,
+    end_time: u64,
+    mint_fee: U256,
+    total_box: u64,
+    max_per_one: u8,
+    whitelist_only: bool,
+}
+
+impl PhaseChanged {
+    pub fn new(phase_id: u64, phase: &Phase) -> Self {
+        Self {
+            phase_id,
+            start_time: phase.start_time,
+            end_time: phase.end_time,
+            mint_fee: phase.mint_fee,
+            total_box: phase.total_box,
+            max_per_one: phase.max_per_one,
+            whitelist_only: phase.whitelist_only,
         }
-        FactoryEvent::MintFactory {
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct OwnershipTransferProposed {
+    owner: Key,
+    pending_owner: Key,
+}
+
+impl OwnershipTransferProposed {
+    pub fn new(owner: Key, pending_owner: Key) -> Self {
+        Self {
             owner,
-            minted,
-            // token_id,
-        } => {
-            let mut event = BTreeMap::new();
-            event.insert("contract_package_hash", package.to_string());
-            event.insert("event_type", pair_event.type_name());
-            event.insert("owner", owner.to_string());
-            event.insert("minted", minted.to_string());
-            // event.insert("value", token.to_string());
-            events.push(event);
+            pending_owner,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct OwnershipTransferCancelled {
+    owner: Key,
+    pending_owner: Key,
+}
+
+impl OwnershipTransferCancelled {
+    pub fn new(owner: Key, pending_owner: Key) -> Self {
+        Self {
+            owner,
+            pending_owner,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct OwnerTransferred {
+    previous_owner: Key,
+    new_owner: Key,
+}
+
+impl OwnerTransferred {
+    pub fn new(previous_owner: Key, new_owner: Key) -> Self {
+        Self {
+            previous_owner,
+            new_owner,
         }
-        FactoryEvent::Withdrawal {
-            cspr_recipient,
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Withdrawal {
+    recipient: Key,
+    from: Key,
+    amount: U256,
+}
+
+impl Withdrawal {
+    pub fn new(recipient: Key, from: Key, amount: U256) -> Self {
+        Self {
+            recipient,
             from,
-            value,
-        } => {
-            let mut event = BTreeMap::new();
-            event.insert("contract_package_hash", package.to_string());
-            event.insert("event_type", pair_event.type_name());
-            event.insert("cspr_recipient", cspr_recipient.to_string());
-            event.insert("from", from.to_string());
-            event.insert("value", value.to_string());
-            events.push(event);
+            amount,
         }
-    };
-    for event in events {
-        let _: URef = storage::new_uref(event);
     }
 }
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Paused {
+    account: Key,
+}
+
+impl Paused {
+    pub fn new(account: Key) -> Self {
+        Self { account }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Unpaused {
+    account: Key,
+}
+
+impl Unpaused {
+    pub fn new(account: Key) -> Self {
+        Self { account }
+    }
+}
+
+pub fn init_events() {
+    let schemas = Schemas::new()
+        .with::<BoxMinted>()
+        .with::<MintPayment>()
+        .with::<FeeChanged>()
+        .with::<PhaseChanged>()
+        .with::<OwnershipTransferProposed>()
+        .with::<OwnershipTransferCancelled>()
+        .with::<OwnerTransferred>()
+        .with::<Withdrawal>()
+        .with::<Paused>()
+        .with::<Unpaused>();
+    casper_event_standard::init(schemas);
+}
@@ -0,0 +1,73 @@
+//! Implementation of the revenue split of the mint proceeds.
+use alloc::vec::Vec;
+use casper_contract::{
+    contract_api::{runtime, system::transfer_from_purse_to_account},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, URef, U256};
+
+use crate::{constants::*, error::Error, helpers};
+
+/// Sum of the shares of all the fee receivers, in basis points.
+pub const TOTAL_FEE_SHARES: u16 = 10_000;
+
+/// Returns the fee receivers along with their share of the proceeds, in basis points.
+pub(crate) fn fee_receivers() -> Vec<(Key, u16)> {
+    helpers::get_stored_value_with_user_errors(
+        FEE_RECEIVERS,
+        Error::MissingFeeReceiver,
+        Error::InvalidFeeReceiver,
+    )
+}
+
+/// Replaces the fee receivers, reverting unless they are distinct accounts with non-zero shares
+/// totalling [`TOTAL_FEE_SHARES`].
+pub(crate) fn set_fee_receivers(fee_receivers: Vec<(Key, u16)>) {
+    let mut total_shares: u16 = 0;
+    for (index, (receiver, share)) in fee_receivers.iter().enumerate() {
+        if receiver.into_account().is_none() {
+            runtime::revert(Error::InputMustBeAccountHash);
+        }
+        if *share == 0 {
+            runtime::revert(Error::InvalidFeeShares);
+        }
+        if fee_receivers[..index]
+            .iter()
+            .any(|(other_receiver, _)| other_receiver == receiver)
+        {
+            runtime::revert(Error::AddressRepeated);
+        }
+        total_shares = total_shares
+            .checked_add(*share)
+            .unwrap_or_revert_with(Error::InvalidFeeShares);
+    }
+    if total_shares != TOTAL_FEE_SHARES {
+        runtime::revert(Error::InvalidFeeShares);
+    }
+    helpers::set_key(FEE_RECEIVERS, fee_receivers);
+}
+
+/// Splits `amount` from `src_purse` among the fee receivers. The last receiver gets the rounding
+/// remainder, so the whole amount is always distributed.
+pub(crate) fn distribute(src_purse: URef, amount: U256) {
+    let fee_receivers = fee_receivers();
+    let mut remaining = amount;
+    for (index, (receiver, share)) in fee_receivers.iter().enumerate() {
+        let receiver_amount = if index + 1 == fee_receivers.len() {
+            remaining
+        } else {
+            amount * U256::from(*share) / U256::from(TOTAL_FEE_SHARES)
+        };
+        remaining -= receiver_amount;
+        if receiver_amount.is_zero() {
+            continue;
+        }
+        transfer_from_purse_to_account(
+            src_purse,
+            receiver.into_account().unwrap_or_revert(),
+            helpers::u256_to_u512(receiver_amount),
+            None,
+        )
+        .unwrap_or_revert_with(Error::CanNotTransferCSPR);
+    }
+}
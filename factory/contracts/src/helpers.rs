@@ -72,6 +72,15 @@ pub(crate) fn make_dictionary_item_key_for_account(account_hash: Key) -> String
     hex::encode(&pre_account)
 }
 
+pub(crate) fn make_dictionary_item_key_for_phase(phase_id: u64, account_hash: Key) -> String {
+    let pre_account = account_hash.into_account().unwrap_or_revert();
+    // The hex-encoded account hash alone already takes the 64 characters allowed for a
+    // dictionary item key, so the phase id and the account hash are hashed together.
+    let mut preimage = phase_id.to_bytes().unwrap_or_revert();
+    preimage.extend_from_slice(pre_account.as_bytes());
+    hex::encode(runtime::blake2b(preimage))
+}
+
 pub(crate) fn get_stored_value_with_user_errors<T: CLTyped + FromBytes>(
     name: &str,
     missing: Error,
@@ -260,6 +269,12 @@ pub fn u256_to_u512(nb: U256) -> U512 {
     U512::from_big_endian(&b)
 }
 
+pub fn u512_to_u256(nb: U512) -> U256 {
+    let mut b = [0u8; 64];
+    nb.to_big_endian(&mut b);
+    U256::from_big_endian(&b[32..64])
+}
+
 pub fn require(v: bool, e: Error) {
     if !v {
         runtime::revert(e);
@@ -12,23 +12,30 @@ pub mod constants;
 mod entry_points;
 mod error;
 pub mod events;
+mod fee_receivers;
 mod helpers;
 pub mod named_keys;
+mod phase;
 
 use crate::{constants::*, error::Error, helpers::*};
-use alloc::{string::String, vec};
+use alloc::{string::String, vec, vec::Vec};
 use casper_contract::{
-    contract_api::{runtime, storage, system::transfer_from_purse_to_account},
+    contract_api::{
+        runtime, storage,
+        system::{self, transfer_from_purse_to_account},
+    },
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    contracts::NamedKeys, runtime_args, ContractPackageHash, HashAddr, Key, RuntimeArgs, URef, U256,
+    contracts::NamedKeys, runtime_args, CLValue, ContractPackageHash, HashAddr, Key, RuntimeArgs,
+    URef, U256,
 };
-use events::FactoryEvent;
-
-pub const MINTING_START_TIME: &str = "minting_start_time";
-pub const MINTING_START_TIME_R3: &str = "minting_start_time_r3";
-pub const MINTING_END_TIME: &str = "minting_end_time";
+use events::{
+    BoxMinted, FeeChanged, MintPayment, OwnerTransferred, OwnershipTransferCancelled,
+    OwnershipTransferProposed, Paused, PhaseChanged, Unpaused, Withdrawal,
+};
+use merkle_proof::Hash;
+use phase::Phase;
 
 #[no_mangle]
 pub extern "C" fn init() {
@@ -36,46 +43,26 @@ pub extern "C" fn init() {
         runtime::revert(Error::ContractAlreadyInitialized);
     }
     let contract_hash: Key = runtime::get_named_arg(ARG_CONTRACT_HASH);
-    let total_box: u64 = runtime::get_named_arg("total_box");
-    let total_box_r3: u64 = runtime::get_named_arg("total_box_r3");
-    let max_per_one: u8 = runtime::get_named_arg("max_per_one");
-    let max_per_one_r3: u8 = runtime::get_named_arg("max_per_one_r3");
-    let start_time: u64 = runtime::get_named_arg(MINTING_START_TIME);
-    let start_time_r3: u64 = runtime::get_named_arg(MINTING_START_TIME_R3);
-    let end_time: u64 = runtime::get_named_arg(MINTING_END_TIME);
     let nft_contract_package: Key = runtime::get_named_arg(ARG_NFT_CONTRACT_PACKAGE);
 
     set_key(CONTRACT_HASH_KEY_NAME, contract_hash);
     set_key(ARG_NFT_CONTRACT_PACKAGE, nft_contract_package);
-    runtime::put_key("total_box", storage::new_uref(total_box as u64).into());
-    runtime::put_key(
-        "total_box_r3",
-        storage::new_uref(total_box_r3 as u64).into(),
-    );
-
-    runtime::put_key("max_per_one", storage::new_uref(max_per_one as u8).into());
-    runtime::put_key(
-        "max_per_one_r3",
-        storage::new_uref(max_per_one_r3 as u8).into(),
-    );
-    runtime::put_key("number_of_minted_box", storage::new_uref(0_u64).into());
-    runtime::put_key("number_of_minted_box_r3", storage::new_uref(0_u64).into());
-    runtime::put_key(
-        MINTING_START_TIME,
-        storage::new_uref(start_time as u64).into(),
-    );
-    runtime::put_key(
-        MINTING_START_TIME_R3,
-        storage::new_uref(start_time_r3 as u64).into(),
-    );
-    runtime::put_key(MINTING_END_TIME, storage::new_uref(end_time as u64).into());
+    runtime::put_key(NUMBER_OF_PHASES, storage::new_uref(0_u64).into());
 
     storage::new_dictionary(ADDRESSES_WHITELIST)
         .unwrap_or_revert_with(Error::FailedToCreateDictionary);
     storage::new_dictionary(NFT_MINTED_NUMBER)
         .unwrap_or_revert_with(Error::FailedToCreateDictionary);
-    storage::new_dictionary(NFT_MINTED_NUMBER_R3)
+    storage::new_dictionary(PHASES).unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    storage::new_dictionary(PHASE_MINTED_BOX)
         .unwrap_or_revert_with(Error::FailedToCreateDictionary);
+    storage::new_dictionary(MERKLE_ROOTS).unwrap_or_revert_with(Error::FailedToCreateDictionary);
+
+    runtime::put_key(CONTRACT_PURSE, system::create_purse().into());
+    runtime::put_key(ACCRUE_PROCEEDS, storage::new_uref(false).into());
+    runtime::put_key(PAUSED_KEY_NAME, storage::new_uref(false).into());
+
+    events::init_events();
 }
 
 #[no_mangle]
@@ -95,67 +82,6 @@ fn call() {
         Error::InvalidFeeReceiver,
     )
     .unwrap_or_revert();
-    let start_time: u64 = helpers::get_named_arg_with_user_errors(
-        MINTING_START_TIME,
-        Error::MissingMintingStart,
-        Error::InvalidMintingStart,
-    )
-    .unwrap_or_revert();
-    let start_time_r3: u64 = helpers::get_named_arg_with_user_errors(
-        MINTING_START_TIME_R3,
-        Error::MissingMintingStart,
-        Error::InvalidMintingStart,
-    )
-    .unwrap_or_revert();
-
-    let end_time: u64 = helpers::get_named_arg_with_user_errors(
-        MINTING_END_TIME,
-        Error::MissingMintingEnd,
-        Error::InvalidMintingEnd,
-    )
-    .unwrap_or_revert();
-
-    let mint_fee: U256 = helpers::get_named_arg_with_user_errors(
-        ARG_MINT_FEE,
-        Error::MissingCsprMintFee,
-        Error::InvalidCsprMintFee,
-    )
-    .unwrap_or_revert();
-
-    let mint_fee_r3: U256 = helpers::get_named_arg_with_user_errors(
-        ARG_MINT_FEE_R3,
-        Error::MissingCsprMintFee,
-        Error::InvalidCsprMintFee,
-    )
-    .unwrap_or_revert();
-
-    let total_box: u64 = helpers::get_named_arg_with_user_errors(
-        "total_box",
-        Error::MissingCsprMintFee,
-        Error::InvalidCsprMintFee,
-    )
-    .unwrap_or_revert();
-
-    let total_box_r3: u64 = helpers::get_named_arg_with_user_errors(
-        "total_box_r3",
-        Error::MissingCsprMintFee,
-        Error::InvalidCsprMintFee,
-    )
-    .unwrap_or_revert();
-
-    let max_per_one: u8 = helpers::get_named_arg_with_user_errors(
-        "max_per_one",
-        Error::MissingCsprMintFee,
-        Error::InvalidCsprMintFee,
-    )
-    .unwrap_or_revert();
-
-    let max_per_one_r3: u8 = helpers::get_named_arg_with_user_errors(
-        "max_per_one_r3",
-        Error::MissingCsprMintFee,
-        Error::InvalidCsprMintFee,
-    )
-    .unwrap_or_revert();
 
     let nft_contract_package: Key = runtime::get_named_arg(ARG_NFT_CONTRACT_PACKAGE);
 
@@ -163,8 +89,6 @@ fn call() {
     let named_keys: NamedKeys = named_keys::default(
         contract_name,
         contract_owner,
-        mint_fee,
-        mint_fee_r3,
         contract_package_hash,
         fee_receiver,
         None,
@@ -187,13 +111,6 @@ fn call() {
         INIT_ENTRY_POINT_NAME,
         runtime_args! {
             "contract_hash" => Key::from(contract_hash),
-            "total_box" => total_box,
-            "total_box_r3" => total_box_r3,
-            "max_per_one" => max_per_one,
-            "max_per_one_r3" => max_per_one_r3,
-            "minting_start_time" => start_time,
-            "minting_start_time_r3" => start_time_r3,
-            "minting_end_time" => end_time,
             ARG_NFT_CONTRACT_PACKAGE => nft_contract_package
         },
     );
@@ -203,12 +120,135 @@ fn call() {
 pub extern "C" fn set_addresses_whitelist() {
     // Check caller must be DEV account
     only_owner();
+    let new_addresses_whitelist: Vec<Key> = helpers::get_named_arg_with_user_errors(
+        ARG_NEW_ADDRESSES_WHITELIST,
+        Error::MissingNewAddressWhitelist,
+        Error::InvalidNewAddressWhitelist,
+    )
+    .unwrap_or_revert_with(Error::CannotGetWhitelistAddrressArg);
+    // true adds the addresses to the whitelist, false removes them
+    let is_whitelist: bool = helpers::get_named_arg_with_user_errors(
+        ARG_IS_WHITELIST,
+        Error::MissingNewAddressWhitelist,
+        Error::InvalidNewAddressWhitelist,
+    )
+    .unwrap_or_revert();
+
+    for address in new_addresses_whitelist {
+        if address.into_account().is_none() {
+            runtime::revert(Error::InputMustBeAccountHash);
+        }
+        if is_whitelist && is_address_whitelisted(address) {
+            runtime::revert(Error::AddressRepeated);
+        }
+        write_dictionary_value_from_key(
+            ADDRESSES_WHITELIST,
+            &make_dictionary_item_key_for_account(address),
+            is_whitelist,
+        );
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn is_whitelisted() {
+    let address: Key = helpers::get_named_arg_with_user_errors(
+        ADDRESS_RUNTIME_ARG_NAME,
+        Error::MissingNewAddressWhitelist,
+        Error::InvalidWhiteListAddress,
+    )
+    .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(is_address_whitelisted(address)).unwrap_or_revert());
+}
+
+// add a new sale phase, or replace an existing one
+#[no_mangle]
+pub extern "C" fn set_phase() {
+    only_owner();
+    let phase_id: u64 = helpers::get_named_arg_with_user_errors(
+        ARG_PHASE_ID,
+        Error::MissingPhaseId,
+        Error::InvalidPhaseId,
+    )
+    .unwrap_or_revert();
+    let start_time: u64 = helpers::get_named_arg_with_user_errors(
+        ARG_MINTING_START_TIME,
+        Error::MissingMintingStart,
+        Error::InvalidMintingStart,
+    )
+    .unwrap_or_revert();
+    let end_time: u64 = helpers::get_named_arg_with_user_errors(
+        ARG_MINTING_END_TIME,
+        Error::MissingMintingEnd,
+        Error::InvalidMintingEnd,
+    )
+    .unwrap_or_revert();
+    let mint_fee: U256 = helpers::get_named_arg_with_user_errors(
+        ARG_MINT_FEE,
+        Error::MissingCsprMintFee,
+        Error::InvalidCsprMintFee,
+    )
+    .unwrap_or_revert();
+    let total_box: u64 = helpers::get_named_arg_with_user_errors(
+        ARG_TOTAL_BOX,
+        Error::MissingTotalBox,
+        Error::InvalidTotalBox,
+    )
+    .unwrap_or_revert();
+    let max_per_one: u8 = helpers::get_named_arg_with_user_errors(
+        ARG_MAX_PER_ONE,
+        Error::MissingMaxPerOne,
+        Error::InvalidMaxPerOne,
+    )
+    .unwrap_or_revert();
+    let whitelist_only: bool = helpers::get_named_arg_with_user_errors(
+        ARG_WHITELIST_ONLY,
+        Error::MissingWhitelistOnly,
+        Error::InvalidWhitelistOnly,
+    )
+    .unwrap_or_revert();
+
+    let phase = Phase {
+        start_time,
+        end_time,
+        mint_fee,
+        total_box,
+        max_per_one,
+        whitelist_only,
+    };
+    phase::set_phase(phase_id, phase.clone());
+    casper_event_standard::emit(PhaseChanged::new(phase_id, &phase));
+}
+
+// set the root of the Merkle allowlist of a phase, `None` removes it
+#[no_mangle]
+pub extern "C" fn set_merkle_root() {
+    only_owner();
+    let phase_id: u64 = helpers::get_named_arg_with_user_errors(
+        ARG_PHASE_ID,
+        Error::MissingPhaseId,
+        Error::InvalidPhaseId,
+    )
+    .unwrap_or_revert();
+    let merkle_root: Option<Hash> = helpers::get_named_arg_with_user_errors(
+        ARG_MERKLE_ROOT,
+        Error::MissingMerkleRoot,
+        Error::InvalidMerkleRoot,
+    )
+    .unwrap_or_revert();
+    phase::set_merkle_root(phase_id, merkle_root);
+}
+
+// returns the phase active at the current block time, if any
+#[no_mangle]
+pub extern "C" fn current_phase() {
+    runtime::ret(CLValue::from_t(phase::current_phase()).unwrap_or_revert());
 }
 
 // mint function of factory
 #[no_mangle]
 pub extern "C" fn mint() {
-    minting_valid_time();
+    when_not_paused();
+    let (phase_id, phase) = phase::current_phase().unwrap_or_revert_with(Error::MintingTimeInvalid);
     let nft_owner: Key = helpers::get_named_arg_with_user_errors(
         "token_owner",
         Error::MissingTokenMetaData,
@@ -226,89 +266,37 @@ pub extern "C" fn mint() {
     )
     .unwrap_or_revert();
 
-    let nft_owner_key = make_dictionary_item_key_for_account(nft_owner: Key);
-
-    let is_round2_finished = is_round2_done();
-
-    let max_per_one_key = if is_round2_finished {
-        "max_per_one_r3"
-    } else {
-        "max_per_one"
-    };
+    let merkle_allocation = merkle_allocation(phase_id, nft_owner);
+    if phase.whitelist_only && merkle_allocation.is_none() && !is_address_whitelisted(nft_owner) {
+        runtime::revert(Error::NotWhitelisted);
+    }
+    let max_per_one = merkle_allocation.unwrap_or(phase.max_per_one);
 
-    let max_per_one: u8 = helpers::get_stored_value_with_user_errors(
-        max_per_one_key,
-        Error::InvalidContext,
-        Error::InvalidContext,
-    );
+    let nft_owner_key = make_dictionary_item_key_for_phase(phase_id, nft_owner);
 
-    let nft_minted_number_key = if is_round2_finished {
-        NFT_MINTED_NUMBER_R3
-    } else {
-        NFT_MINTED_NUMBER
-    };
-
-    let nft_minted =
-        match get_dictionary_value_from_key::<u8>(nft_minted_number_key, &nft_owner_key) {
-            Some(minted) => minted as u8,
-            None => 0u8,
-        };
-    if nft_minted + count > max_per_one {
+    let nft_minted = get_dictionary_value_from_key::<u8>(NFT_MINTED_NUMBER, &nft_owner_key)
+        .unwrap_or_default()
+        .checked_add(count)
+        .unwrap_or_revert_with(Error::ReachMaximumNumberOfMinting);
+    if nft_minted > max_per_one {
         runtime::revert(Error::ReachMaximumNumberOfMinting);
     }
 
-    let number_of_minted_box_key = if is_round2_finished {
-        "number_of_minted_box_r3"
-    } else {
-        "number_of_minted_box"
-    };
-    let number_of_minted_box: u64 = helpers::get_stored_value_with_user_errors(
-        number_of_minted_box_key,
-        Error::InvalidContext,
-        Error::InvalidContext,
-    );
-
-    let total_box_key = if is_round2_finished {
-        "total_box_r3"
-    } else {
-        "total_box"
-    };
-
-    let total_box: u64 = helpers::get_stored_value_with_user_errors(
-        total_box_key,
-        Error::InvalidContext,
-        Error::InvalidContext,
-    );
-
-    if number_of_minted_box + (count as u64) > total_box {
-        runtime::revert(Error::InvalidContext);
+    let phase_key = phase_id.to_string();
+    let number_of_minted_box = get_dictionary_value_from_key::<u64>(PHASE_MINTED_BOX, &phase_key)
+        .unwrap_or_default()
+        + (count as u64);
+    if number_of_minted_box > phase.total_box {
+        runtime::revert(Error::PhaseBoxLimitExceeded);
     }
 
-    let fee_receiver: Key = helpers::get_stored_value_with_user_errors::<Key>(
-        FEE_RECEIVER,
-        Error::MissingFeeReceiver,
-        Error::InvalidFeeReceiver,
-    );
-
-    let mint_fee_key = if is_round2_finished {
-        MINT_FEE_R3
-    } else {
-        MINT_FEE
-    };
-
-    let wcspr_mint_fee: U256 = helpers::get_stored_value_with_user_errors::<U256>(
-        mint_fee_key,
-        Error::MissingCsprMintFee,
-        Error::InvalidCsprMintFee,
-    );
-
     let allowed_cspr_amount = helpers::get_named_arg_with_user_errors::<U256>(
         AMOUNT_RUNTIME_ARG_NAME,
         Error::MissingAmount,
         Error::InvalidAmount,
     )
     .unwrap_or_revert_with(Error::CannotGetAmount);
-    let required_amount: U256 = wcspr_mint_fee * U256::from(count as u8);
+    let required_amount: U256 = phase.mint_fee * U256::from(count as u8);
     if allowed_cspr_amount < required_amount {
         runtime::revert(Error::NotEnoughAmount)
     }
@@ -319,103 +307,164 @@ pub extern "C" fn mint() {
     )
     .unwrap_or_revert_with(Error::CanNotGetUserPurse); //Contract hash of NFT CASPERPUNK
 
-    transfer_from_purse_to_account(
-        src_purse,
-        fee_receiver.into_account().unwrap(),
-        u256_to_u512(required_amount),
-        None,
-    )
-    .unwrap_or_revert_with(Error::CanNotTransferCSPR);
+    if accrue_proceeds() {
+        system::transfer_from_purse_to_purse(
+            src_purse,
+            contract_purse(),
+            u256_to_u512(required_amount),
+            None,
+        )
+        .unwrap_or_revert_with(Error::CanNotTransferCSPR);
+    } else {
+        fee_receivers::distribute(src_purse, required_amount);
+    }
+
+    // return any overpayment to the buyer
+    let buyer: Key = helpers::get_verified_caller().unwrap_or_revert();
+    let refunded_amount: U256 = allowed_cspr_amount - required_amount;
+    if !refunded_amount.is_zero() {
+        transfer_from_purse_to_account(
+            src_purse,
+            buyer
+                .into_account()
+                .unwrap_or_revert_with(Error::CallerMustBeAccountHash),
+            u256_to_u512(refunded_amount),
+            None,
+        )
+        .unwrap_or_revert_with(Error::CanNotTransferCSPR);
+    }
 
     let nft_contract_package: Key = helpers::get_key(ARG_NFT_CONTRACT_PACKAGE).unwrap();
 
     call_cep78_mint(&nft_contract_package, nft_owner, count);
 
-    write_dictionary_value_from_key(
-        nft_minted_number_key,
-        &nft_owner_key,
-        (nft_minted + count) as u8,
-    );
+    write_dictionary_value_from_key(NFT_MINTED_NUMBER, &nft_owner_key, nft_minted);
+    write_dictionary_value_from_key(PHASE_MINTED_BOX, &phase_key, number_of_minted_box);
+    casper_event_standard::emit(BoxMinted::new(nft_owner, phase_id, count, nft_minted));
+    casper_event_standard::emit(MintPayment::new(
+        buyer,
+        allowed_cspr_amount,
+        required_amount,
+        refunded_amount,
+    ));
+}
 
-    set_key(
-        number_of_minted_box_key,
-        number_of_minted_box + (count as u64),
-    );
-    events::emit(&FactoryEvent::MintFactory {
-        owner: nft_owner,
-        minted: (nft_minted + count) as u8,
-    });
+// first step of an ownership transfer, the new owner has to accept it
+#[no_mangle]
+pub extern "C" fn propose_owner() {
+    only_owner();
+    let pending_owner: Key = helpers::get_named_arg_with_user_errors(
+        ARG_CONTRACT_OWNER,
+        Error::MissingContractOwner,
+        Error::InvalidContractOwner,
+    )
+    .unwrap_or_revert();
+    set_key(PENDING_OWNER_KEY_NAME, Some(pending_owner));
+    casper_event_standard::emit(OwnershipTransferProposed::new(
+        owner_internal(),
+        pending_owner,
+    ));
 }
 
 #[no_mangle]
-pub extern "C" fn transfer_owner() {
+pub extern "C" fn accept_ownership() {
+    let pending_owner = pending_owner_internal().unwrap_or_revert_with(Error::NoPendingOwner);
+    if pending_owner != helpers::get_verified_caller().unwrap_or_revert() {
+        runtime::revert(Error::NotPendingOwner);
+    }
+    let previous_owner = owner_internal();
+    set_key(CONTRACT_OWNER_KEY_NAME, pending_owner);
+    set_key::<Option<Key>>(PENDING_OWNER_KEY_NAME, None);
+    casper_event_standard::emit(OwnerTransferred::new(previous_owner, pending_owner));
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_ownership_transfer() {
     only_owner();
-    let new_contract_owner: Key = runtime::get_named_arg(ARG_CONTRACT_OWNER);
-    set_key(CONTRACT_OWNER_KEY_NAME, new_contract_owner);
+    let pending_owner = pending_owner_internal().unwrap_or_revert_with(Error::NoPendingOwner);
+    set_key::<Option<Key>>(PENDING_OWNER_KEY_NAME, None);
+    casper_event_standard::emit(OwnershipTransferCancelled::new(
+        owner_internal(),
+        pending_owner,
+    ));
 }
 
+// send all the proceeds to a single fee receiver
 #[no_mangle]
 pub extern "C" fn change_fee_receiver() {
     only_owner();
     let new_fee_receiver: Key = runtime::get_named_arg(ARG_FEE_RECEIVER);
-    set_key(FEE_RECEIVER, new_fee_receiver);
+    update_fee_receivers(vec![(new_fee_receiver, fee_receivers::TOTAL_FEE_SHARES)]);
 }
 
+// split the proceeds among several fee receivers, shares are in basis points
 #[no_mangle]
-pub extern "C" fn change_mint_fee() {
+pub extern "C" fn set_fee_receivers() {
     only_owner();
-    let new_wcspr_mint_fee: U256 = runtime::get_named_arg(ARG_MINT_FEE);
-    set_key(MINT_FEE, new_wcspr_mint_fee);
+    let new_fee_receivers: Vec<(Key, u16)> = helpers::get_named_arg_with_user_errors(
+        ARG_FEE_RECEIVERS,
+        Error::MissingFeeReceiver,
+        Error::InvalidFeeReceiver,
+    )
+    .unwrap_or_revert();
+    update_fee_receivers(new_fee_receivers);
 }
 
+// keep the proceeds in the contract purse instead of sending them to the fee receivers
 #[no_mangle]
-pub extern "C" fn change_mint_fee_r3() {
+pub extern "C" fn set_accrue_proceeds() {
     only_owner();
-    let new_wcspr_mint_fee: U256 = runtime::get_named_arg(ARG_MINT_FEE_R3);
-    set_key(MINT_FEE_R3, new_wcspr_mint_fee);
+    let accrue_proceeds: bool = helpers::get_named_arg_with_user_errors(
+        ARG_ACCRUE_PROCEEDS,
+        Error::MissingAccrueProceeds,
+        Error::InvalidAccrueProceeds,
+    )
+    .unwrap_or_revert();
+    set_key(ACCRUE_PROCEEDS, accrue_proceeds);
 }
 
+// withdraw accrued proceeds from the contract purse
 #[no_mangle]
-pub extern "C" fn update_mint_params() {
+pub extern "C" fn withdraw() {
     only_owner();
-    let start_time: u64 = helpers::get_named_arg_with_user_errors(
-        MINTING_START_TIME,
-        Error::MissingMintingStart,
-        Error::InvalidMintingStart,
+    let amount: U256 = helpers::get_named_arg_with_user_errors(
+        AMOUNT_RUNTIME_ARG_NAME,
+        Error::MissingAmount,
+        Error::InvalidAmount,
     )
     .unwrap_or_revert();
-    let start_time_r3: u64 = helpers::get_named_arg_with_user_errors(
-        MINTING_START_TIME_R3,
-        Error::MissingMintingStart,
-        Error::InvalidMintingStart,
-    )
-    .unwrap_or_revert();
-    let end_time: u64 = helpers::get_named_arg_with_user_errors(
-        MINTING_END_TIME,
-        Error::MissingMintingEnd,
-        Error::InvalidMintingEnd,
+    let recipient: Key = helpers::get_named_arg_with_user_errors(
+        RECIPIENT_RUNTIME_ARG_NAME,
+        Error::MissingRecipient,
+        Error::InvalidRecipient,
     )
     .unwrap_or_revert();
+    let cspr_recipient = recipient
+        .into_account()
+        .unwrap_or_revert_with(Error::InputMustBeAccountHash);
 
-    let minting_price: U256 = helpers::get_named_arg_with_user_errors(
-        MINT_FEE,
-        Error::MissingCsprMintFee,
-        Error::InvalidCsprMintFee,
-    )
-    .unwrap_or_revert();
+    if accrued_balance_internal() < amount {
+        runtime::revert(Error::InsufficientContractBalance);
+    }
+    transfer_from_purse_to_account(contract_purse(), cspr_recipient, u256_to_u512(amount), None)
+        .unwrap_or_revert_with(Error::CanNotTransferCSPR);
+
+    casper_event_standard::emit(Withdrawal::new(
+        recipient,
+        helpers::get_verified_caller().unwrap_or_revert(),
+        amount,
+    ));
+}
 
-    let minting_price_r3: U256 = helpers::get_named_arg_with_user_errors(
-        MINT_FEE_R3,
-        Error::MissingCsprMintFee,
-        Error::InvalidCsprMintFee,
-    )
-    .unwrap_or_revert();
+// returns the proceeds accrued in the contract purse
+#[no_mangle]
+pub extern "C" fn accrued_balance() {
+    runtime::ret(CLValue::from_t(accrued_balance_internal()).unwrap_or_revert());
+}
 
-    helpers::set_key(MINTING_START_TIME, start_time);
-    helpers::set_key(MINTING_START_TIME_R3, start_time_r3);
-    helpers::set_key(MINTING_END_TIME, end_time);
-    helpers::set_key(MINT_FEE, minting_price);
-    helpers::set_key(MINT_FEE_R3, minting_price_r3);
+fn update_fee_receivers(new_fee_receivers: Vec<(Key, u16)>) {
+    fee_receivers::set_fee_receivers(new_fee_receivers.clone());
+    casper_event_standard::emit(FeeChanged::new(new_fee_receivers));
 }
 
 fn call_cep78_mint(nft_contract_package: &Key, owner: Key, count: u8) {
@@ -436,33 +485,89 @@ fn call_cep78_mint(nft_contract_package: &Key, owner: Key, count: u8) {
     );
 }
 
-fn is_round2_done() -> bool {
-    let start_time_r3: u64 = helpers::get_stored_value_with_user_errors(
-        MINTING_START_TIME_R3,
-        Error::MissingMintingStart,
-        Error::InvalidMintingStart,
-    );
-    let current_time_sec = helpers::current_block_timestamp_sec();
-    current_time_sec >= start_time_r3
+fn is_address_whitelisted(address: Key) -> bool {
+    // only account hashes can be whitelisted
+    if address.into_account().is_none() {
+        return false;
+    }
+    get_dictionary_value_from_key::<bool>(
+        ADDRESSES_WHITELIST,
+        &make_dictionary_item_key_for_account(address),
+    )
+    .unwrap_or_default()
 }
 
-pub fn minting_valid_time() {
-    let start_time: u64 = helpers::get_stored_value_with_user_errors(
-        MINTING_START_TIME,
-        Error::MissingMintingStart,
-        Error::InvalidMintingStart,
-    );
-    let end_time: u64 = helpers::get_stored_value_with_user_errors(
-        MINTING_END_TIME,
-        Error::MissingMintingEnd,
-        Error::InvalidMintingEnd,
+// Returns the allocation proven by the optional `proof` and `allocation` args against the Merkle
+// root of the phase, reverting if the proof is invalid.
+fn merkle_allocation(phase_id: u64, nft_owner: Key) -> Option<u8> {
+    get_named_arg_size(ARG_PROOF)?;
+    let proof: Vec<Hash> = helpers::get_named_arg_with_user_errors(
+        ARG_PROOF,
+        Error::InvalidMerkleProof,
+        Error::InvalidMerkleProof,
+    )
+    .unwrap_or_revert();
+    let allocation: u8 = helpers::get_named_arg_with_user_errors(
+        ARG_ALLOCATION,
+        Error::MissingAllocation,
+        Error::InvalidAllocation,
+    )
+    .unwrap_or_revert();
+    let merkle_root = phase::merkle_root(phase_id).unwrap_or_revert_with(Error::MissingMerkleRoot);
+
+    let account = nft_owner.into_account().unwrap_or_revert();
+    let hasher = |data: &[u8]| runtime::blake2b(data);
+    let leaf = merkle_proof::leaf_hash(&hasher, &account.value(), allocation);
+    helpers::require(
+        merkle_proof::verify(&hasher, &proof, &merkle_root, leaf),
+        Error::InvalidMerkleProof,
     );
-    let current_time_sec = helpers::current_block_timestamp_sec();
+    Some(allocation)
+}
+
+fn contract_purse() -> URef {
+    runtime::get_key(CONTRACT_PURSE)
+        .and_then(|key| key.into_uref())
+        .unwrap_or_revert_with(Error::CanNotGetContractPurse)
+}
+
+fn accrue_proceeds() -> bool {
+    get_key(ACCRUE_PROCEEDS).unwrap_or_default()
+}
+
+fn accrued_balance_internal() -> U256 {
+    u512_to_u256(
+        system::get_purse_balance(contract_purse())
+            .unwrap_or_revert_with(Error::CanNotGetContractPurse),
+    )
+}
+
+// stop sales until `unpause` is called
+#[no_mangle]
+pub extern "C" fn pause() {
+    only_owner();
+    set_key(PAUSED_KEY_NAME, true);
+    casper_event_standard::emit(Paused::new(
+        helpers::get_verified_caller().unwrap_or_revert(),
+    ));
+}
+
+#[no_mangle]
+pub extern "C" fn unpause() {
+    only_owner();
+    set_key(PAUSED_KEY_NAME, false);
+    casper_event_standard::emit(Unpaused::new(
+        helpers::get_verified_caller().unwrap_or_revert(),
+    ));
+}
+
+fn when_not_paused() {
     helpers::require(
-        start_time <= current_time_sec && current_time_sec <= end_time,
-        Error::MintingTimeInvalid,
+        !get_key::<bool>(PAUSED_KEY_NAME).unwrap_or_default(),
+        Error::ContractPaused,
     );
 }
+
 pub fn only_owner() {
     helpers::require(
         owner_internal() == helpers::get_verified_caller().unwrap_or_revert(),
@@ -470,6 +575,10 @@ pub fn only_owner() {
     );
 }
 
+pub fn pending_owner_internal() -> Option<Key> {
+    get_key::<Option<Key>>(PENDING_OWNER_KEY_NAME).flatten()
+}
+
 pub fn owner_internal() -> Key {
     let owner_key: Key = helpers::get_stored_value_with_user_errors::<Key>(
         CONTRACT_OWNER_KEY_NAME,
@@ -1,13 +1,14 @@
-use alloc::string::{String, ToString};
+use alloc::{
+    string::{String, ToString},
+    vec,
+};
 use casper_contract::contract_api::storage;
-use casper_types::{contracts::NamedKeys, ContractPackageHash, Key, U256};
+use casper_types::{contracts::NamedKeys, ContractPackageHash, Key};
 
-use crate::constants::*;
+use crate::{constants::*, fee_receivers::TOTAL_FEE_SHARES};
 pub fn default(
     nft_factory_contract_name: String,
     contract_owner: Key,
-    wcspr_mint_fee: U256,
-    wcspr_mint_fee_r3: U256,
     contract_package_hash: ContractPackageHash,
     fee_receiver: Key,
     _fee_token: Option<Key>,
@@ -25,18 +26,8 @@ pub fn default(
     );
     // named_keys.insert(DEV.to_string(), Key::from(storage::new_uref(dev)));
     named_keys.insert(
-        FEE_RECEIVER.to_string(),
-        Key::from(storage::new_uref(fee_receiver)),
-    );
-
-    named_keys.insert(
-        MINT_FEE.to_string(),
-        storage::new_uref(wcspr_mint_fee).into(),
-    );
-
-    named_keys.insert(
-        MINT_FEE_R3.to_string(),
-        storage::new_uref(wcspr_mint_fee_r3).into(),
+        FEE_RECEIVERS.to_string(),
+        Key::from(storage::new_uref(vec![(fee_receiver, TOTAL_FEE_SHARES)])),
     );
 
     named_keys.insert(
@@ -0,0 +1,157 @@
+//! Implementation of the sale phases of the factory.
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, U256,
+};
+
+use merkle_proof::Hash;
+
+use crate::{constants::*, error::Error, helpers};
+
+/// A sale phase, active from `start_time` to `end_time` (inclusive, in seconds).
+#[derive(Clone)]
+pub struct Phase {
+    pub start_time: u64,
+    pub end_time: u64,
+    /// Price of a single box.
+    pub mint_fee: U256,
+    /// Number of boxes which can be minted during the phase.
+    pub total_box: u64,
+    /// Number of boxes a single account can mint during the phase.
+    pub max_per_one: u8,
+    /// Whether only whitelisted accounts can mint during the phase.
+    pub whitelist_only: bool,
+}
+
+impl Phase {
+    pub fn is_active(&self, current_time_sec: u64) -> bool {
+        self.start_time <= current_time_sec && current_time_sec <= self.end_time
+    }
+
+    pub fn overlaps(&self, other: &Phase) -> bool {
+        self.start_time <= other.end_time && other.start_time <= self.end_time
+    }
+}
+
+impl ToBytes for Phase {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.start_time.to_bytes()?);
+        result.extend(self.end_time.to_bytes()?);
+        result.extend(self.mint_fee.to_bytes()?);
+        result.extend(self.total_box.to_bytes()?);
+        result.extend(self.max_per_one.to_bytes()?);
+        result.extend(self.whitelist_only.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.start_time.serialized_length()
+            + self.end_time.serialized_length()
+            + self.mint_fee.serialized_length()
+            + self.total_box.serialized_length()
+            + self.max_per_one.serialized_length()
+            + self.whitelist_only.serialized_length()
+    }
+}
+
+impl FromBytes for Phase {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (start_time, remainder) = u64::from_bytes(bytes)?;
+        let (end_time, remainder) = u64::from_bytes(remainder)?;
+        let (mint_fee, remainder) = U256::from_bytes(remainder)?;
+        let (total_box, remainder) = u64::from_bytes(remainder)?;
+        let (max_per_one, remainder) = u8::from_bytes(remainder)?;
+        let (whitelist_only, remainder) = bool::from_bytes(remainder)?;
+        let phase = Phase {
+            start_time,
+            end_time,
+            mint_fee,
+            total_box,
+            max_per_one,
+            whitelist_only,
+        };
+        Ok((phase, remainder))
+    }
+}
+
+// serialized like ((start_time, end_time, mint_fee), (total_box, max_per_one, whitelist_only)),
+// so clients can decode a phase with the plain tuple types
+impl CLTyped for Phase {
+    fn cl_type() -> CLType {
+        CLType::Tuple2([
+            Box::new(CLType::Tuple3([
+                Box::new(CLType::U64),
+                Box::new(CLType::U64),
+                Box::new(CLType::U256),
+            ])),
+            Box::new(CLType::Tuple3([
+                Box::new(CLType::U64),
+                Box::new(CLType::U8),
+                Box::new(CLType::Bool),
+            ])),
+        ])
+    }
+}
+
+pub(crate) fn number_of_phases() -> u64 {
+    helpers::get_stored_value_with_user_errors(
+        NUMBER_OF_PHASES,
+        Error::MissingNumberOfPhases,
+        Error::InvalidNumberOfPhases,
+    )
+}
+
+pub(crate) fn get_phase(phase_id: u64) -> Option<Phase> {
+    helpers::get_dictionary_value_from_key(PHASES, &phase_id.to_string())
+}
+
+/// Returns the phase which is active at the current block time, along with its id.
+pub(crate) fn current_phase() -> Option<(u64, Phase)> {
+    let current_time_sec = helpers::current_block_timestamp_sec();
+    (0..number_of_phases())
+        .filter_map(|phase_id| get_phase(phase_id).map(|phase| (phase_id, phase)))
+        .find(|(_, phase)| phase.is_active(current_time_sec))
+}
+
+/// Replaces the phase stored under `phase_id`, or appends a new one if `phase_id` is the current
+/// number of phases.
+pub(crate) fn set_phase(phase_id: u64, phase: Phase) {
+    let number_of_phases = number_of_phases();
+    if phase_id > number_of_phases {
+        runtime::revert(Error::InvalidPhaseId);
+    }
+    if phase.start_time > phase.end_time {
+        runtime::revert(Error::InvalidMintingEnd);
+    }
+    for other_phase_id in 0..number_of_phases {
+        if other_phase_id == phase_id {
+            continue;
+        }
+        let other_phase = get_phase(other_phase_id).unwrap_or_revert_with(Error::InvalidPhaseId);
+        if phase.overlaps(&other_phase) {
+            runtime::revert(Error::PhasesOverlap);
+        }
+    }
+
+    helpers::write_dictionary_value_from_key(PHASES, &phase_id.to_string(), phase);
+    if phase_id == number_of_phases {
+        helpers::set_key(NUMBER_OF_PHASES, number_of_phases + 1);
+    }
+}
+
+/// Returns the root of the Merkle allowlist of the phase, if one was set.
+pub(crate) fn merkle_root(phase_id: u64) -> Option<Hash> {
+    helpers::get_dictionary_value_from_key::<Option<Hash>>(MERKLE_ROOTS, &phase_id.to_string())
+        .flatten()
+}
+
+/// Sets the root of the Merkle allowlist of an existing phase, or removes it with `None`.
+pub(crate) fn set_merkle_root(phase_id: u64, merkle_root: Option<Hash>) {
+    if phase_id >= number_of_phases() {
+        runtime::revert(Error::InvalidPhaseId);
+    }
+    helpers::write_dictionary_value_from_key(MERKLE_ROOTS, &phase_id.to_string(), merkle_root);
+}
@@ -0,0 +1,126 @@
+//! Merkle proof verification for the factory allowlist.
+//!
+//! The hash function is supplied by the caller, so the contract can use the host's `blake2b`
+//! while the tests run natively.
+#![no_std]
+
+/// A 32 bytes hash, either a leaf or a node of the tree.
+pub type Hash = [u8; 32];
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// Computes the leaf of an allowlist entry, made of the account hash bytes and the number of
+/// boxes the account is allowed to mint.
+pub fn leaf_hash<H: Fn(&[u8]) -> Hash>(hasher: &H, account: &[u8; 32], allocation: u8) -> Hash {
+    let mut preimage = [0u8; 34];
+    preimage[0] = LEAF_PREFIX;
+    preimage[1..33].copy_from_slice(account);
+    preimage[33] = allocation;
+    hasher(&preimage)
+}
+
+/// Computes the parent of two nodes. Nodes are sorted before being hashed, so proofs don't need
+/// to carry the position of each sibling.
+pub fn node_hash<H: Fn(&[u8]) -> Hash>(hasher: &H, a: &Hash, b: &Hash) -> Hash {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut preimage = [0u8; 65];
+    preimage[0] = NODE_PREFIX;
+    preimage[1..33].copy_from_slice(left);
+    preimage[33..65].copy_from_slice(right);
+    hasher(&preimage)
+}
+
+/// Computes the root reached from `leaf` by following `proof`.
+pub fn process_proof<H: Fn(&[u8]) -> Hash>(hasher: &H, proof: &[Hash], leaf: Hash) -> Hash {
+    proof
+        .iter()
+        .fold(leaf, |node, sibling| node_hash(hasher, &node, sibling))
+}
+
+/// Returns `true` if `proof` proves that `leaf` is part of the tree of the given `root`.
+pub fn verify<H: Fn(&[u8]) -> Hash>(hasher: &H, proof: &[Hash], root: &Hash, leaf: Hash) -> bool {
+    process_proof(hasher, proof, leaf) == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blake2::{
+        digest::{Update, VariableOutput},
+        VarBlake2b,
+    };
+
+    fn blake2b(data: &[u8]) -> Hash {
+        let mut result = [0u8; 32];
+        let mut hasher = VarBlake2b::new(32).unwrap();
+        hasher.update(data);
+        hasher.finalize_variable(|hash| result.copy_from_slice(hash));
+        result
+    }
+
+    fn leaves() -> [Hash; 4] {
+        [
+            leaf_hash(&blake2b, &[1u8; 32], 1),
+            leaf_hash(&blake2b, &[2u8; 32], 2),
+            leaf_hash(&blake2b, &[3u8; 32], 5),
+            leaf_hash(&blake2b, &[4u8; 32], 10),
+        ]
+    }
+
+    fn root(leaves: &[Hash; 4]) -> Hash {
+        node_hash(
+            &blake2b,
+            &node_hash(&blake2b, &leaves[0], &leaves[1]),
+            &node_hash(&blake2b, &leaves[2], &leaves[3]),
+        )
+    }
+
+    #[test]
+    fn should_verify_every_leaf() {
+        let leaves = leaves();
+        let root = root(&leaves);
+        let left = node_hash(&blake2b, &leaves[0], &leaves[1]);
+        let right = node_hash(&blake2b, &leaves[2], &leaves[3]);
+
+        assert!(verify(&blake2b, &[leaves[1], right], &root, leaves[0]));
+        assert!(verify(&blake2b, &[leaves[0], right], &root, leaves[1]));
+        assert!(verify(&blake2b, &[leaves[3], left], &root, leaves[2]));
+        assert!(verify(&blake2b, &[leaves[2], left], &root, leaves[3]));
+    }
+
+    #[test]
+    fn should_verify_single_leaf_tree() {
+        let leaf = leaf_hash(&blake2b, &[7u8; 32], 3);
+        assert!(verify(&blake2b, &[], &leaf, leaf));
+    }
+
+    #[test]
+    fn should_reject_wrong_allocation() {
+        let leaves = leaves();
+        let root = root(&leaves);
+        let right = node_hash(&blake2b, &leaves[2], &leaves[3]);
+        let forged_leaf = leaf_hash(&blake2b, &[1u8; 32], 2);
+
+        assert!(!verify(&blake2b, &[leaves[1], right], &root, forged_leaf));
+    }
+
+    #[test]
+    fn should_reject_wrong_account() {
+        let leaves = leaves();
+        let root = root(&leaves);
+        let right = node_hash(&blake2b, &leaves[2], &leaves[3]);
+        let forged_leaf = leaf_hash(&blake2b, &[9u8; 32], 1);
+
+        assert!(!verify(&blake2b, &[leaves[1], right], &root, forged_leaf));
+    }
+
+    #[test]
+    fn should_reject_truncated_proof() {
+        let leaves = leaves();
+        let root = root(&leaves);
+
+        assert!(!verify(&blake2b, &[leaves[1]], &root, leaves[0]));
+        assert!(!verify(&blake2b, &[], &root, leaves[0]));
+    }
+}
@@ -61,15 +61,7 @@ const test = async () => {
     "contract_owner": createRecipientAddress(CLPublicKey.fromHex(dev)), //ABB
     "dev": createRecipientAddress(CLPublicKey.fromHex(dev)), // ABB
     "fee_receiver": createRecipientAddress(CLPublicKey.fromHex(feeReceiver)), // ABB
-    "mint_fee": CLValueBuilder.u256("3000000000000"), // 8 cspr
-    "total_box": CLValueBuilder.u64("1000"),
-    "max_per_one": CLValueBuilder.u8("10"),
-    "minting_start_time": CLValueBuilder.u64("1681300727"),
-    "minting_end_time": CLValueBuilder.u64(1681300727 + 1800),
-    "mint_fee_r3": CLValueBuilder.u256("4000000000000"), // 8 cspr
-    "total_box_r3": CLValueBuilder.u64("4000"),
-    "max_per_one_r3": CLValueBuilder.u8("25"),
-    "minting_start_time_r3": CLValueBuilder.u64(1681300727 + 900),
+    // sale phases are added after install with set_phase, see ownerAction.js
     "nft_contract_package": boxKey
 
   });
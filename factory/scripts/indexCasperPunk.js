@@ -96,7 +96,7 @@ const CSPFactory = class {
     ]);
   }
 
-  async transferOwner({
+  async proposeOwner({
     keys,
     newOwner,
     paymentAmount,
@@ -118,7 +118,48 @@ const CSPFactory = class {
     while (true) {
       try {
         let hash = await this.contractClient.contractCall({
-          entryPoint: "transfer_owner",
+          entryPoint: "propose_owner",
+          keys: keys,
+          paymentAmount,
+          runtimeArgs,
+          cb: (deployHash) => {
+            console.log("deployHash", deployHash);
+          },
+          ttl,
+        });
+
+        return hash;
+      } catch (e) {
+        trial--
+        if (trial == 0) {
+          throw e;
+        }
+        console.log('waiting 3 seconds')
+        await sleep(3000)
+      }
+    }
+  }
+
+  async acceptOwnership({
+    keys,
+    paymentAmount,
+    ttl,
+  }) {
+
+    if (!paymentAmount) {
+      paymentAmount = paymentAmount ? paymentAmount : "1000000000";
+      ttl = ttl ? ttl : DEFAULT_TTL;
+    }
+    let runtimeArgs = {};
+    runtimeArgs = RuntimeArgs.fromMap({})
+    console.log("sending");
+    console.log(paymentAmount)
+    console.log(ttl)
+    let trial = 5;
+    while (true) {
+      try {
+        let hash = await this.contractClient.contractCall({
+          entryPoint: "accept_ownership",
           keys: keys,
           paymentAmount,
           runtimeArgs,
@@ -184,9 +225,15 @@ const CSPFactory = class {
     }
   }
 
-  async changeMintFee({
+  async setPhase({
     keys,
-    newFee,
+    phaseId,
+    mintingStart,
+    mintingEnd,
+    mintFee,
+    totalBox,
+    maxPerOne,
+    whitelistOnly,
     paymentAmount,
     ttl,
   }) {
@@ -197,7 +244,13 @@ const CSPFactory = class {
     }
     let runtimeArgs = {};
     runtimeArgs = RuntimeArgs.fromMap({
-      wcspr_mint_fee: CLValueBuilder.u256(newFee),
+      phase_id: CLValueBuilder.u64(phaseId),
+      minting_start_time: CLValueBuilder.u64(mintingStart),
+      minting_end_time: CLValueBuilder.u64(mintingEnd),
+      mint_fee: CLValueBuilder.u256(mintFee),
+      total_box: CLValueBuilder.u64(totalBox),
+      max_per_one: CLValueBuilder.u8(maxPerOne),
+      whitelist_only: CLValueBuilder.bool(whitelistOnly),
     })
     console.log("sending");
     console.log(paymentAmount)
@@ -206,7 +259,7 @@ const CSPFactory = class {
     while (true) {
       try {
         let hash = await this.contractClient.contractCall({
-          entryPoint: "change_mint_fee",
+          entryPoint: "set_phase",
           keys: keys,
           paymentAmount,
           runtimeArgs,
@@ -67,30 +67,46 @@ const test = async () => {
   let factory = await sdk.CSPFactory.createInstance(factoryHash, NODE_ADDRESS, CHAIN_NAME)
 
 
-  // transfer owner
+  // transfer owner, the new owner has to accept it
 
-  let hashTransferOwner = await factory.transferOwner({
+  let hashProposeOwner = await factory.proposeOwner({
     keys: KEYS,
     newOwner: newOwnerPub,
   })
 
-  console.log(`... hashTransferOwner installation deployHash: ${hashTransferOwner}`);
+  console.log(`... hashProposeOwner installation deployHash: ${hashProposeOwner}`);
 
-  await getDeploy(NODE_ADDRESS, hashTransferOwner);
+  await getDeploy(NODE_ADDRESS, hashProposeOwner);
+
+  let hashAcceptOwnership = await factory.acceptOwnership({
+    keys: KEYSTony,
+  })
+
+  console.log(`... hashAcceptOwnership installation deployHash: ${hashAcceptOwnership}`);
+
+  await getDeploy(NODE_ADDRESS, hashAcceptOwnership);
 
   console.log(`... hashTransferOwner installed successfully.`);
 
 
   // Transfer owner back
 
-  let hashTransferOwner1 = await factory.transferOwner({
+  let hashProposeOwner1 = await factory.proposeOwner({
     keys: KEYSTony,
     newOwner: oldOwnerPub,
   })
 
-  console.log(`... Transfer owner back installation deployHash: ${hashTransferOwner1}`);
+  console.log(`... Transfer owner back installation deployHash: ${hashProposeOwner1}`);
+
+  await getDeploy(NODE_ADDRESS, hashProposeOwner1);
+
+  let hashAcceptOwnership1 = await factory.acceptOwnership({
+    keys: KEYS,
+  })
+
+  console.log(`... Accept owner back installation deployHash: ${hashAcceptOwnership1}`);
 
-  await getDeploy(NODE_ADDRESS, hashTransferOwner1);
+  await getDeploy(NODE_ADDRESS, hashAcceptOwnership1);
 
   console.log(`... Transfer owner back installed successfully.`);
 
@@ -156,11 +172,17 @@ const test = async () => {
   
 
 
-  // Change mint fee 
+  // Change mint fee of the first phase
 
-  let hashChangeFee = await factory.changeMintFee({
+  let hashChangeFee = await factory.setPhase({
     keys: KEYS,
-    newFee: "10000000000",
+    phaseId: 0,
+    mintingStart: 1681300727,
+    mintingEnd: 1681300727 + 1800,
+    mintFee: "10000000000",
+    totalBox: 1000,
+    maxPerOne: 10,
+    whitelistOnly: false,
   })
 
   console.log(`... hashChangeFee installation deployHash: ${hashChangeFee}`);
@@ -8,9 +8,12 @@ mod error;
 use crate::{converters::u512_to_u256, error::Error};
 use contract::{
     contract_api::{account, runtime, system},
+    ext_ffi,
     unwrap_or_revert::UnwrapOrRevert,
 };
-use types::{runtime_args, ContractHash, HashAddr, Key, RuntimeArgs, URef, U256, U512};
+use types::{
+    api_error, runtime_args, ApiError, ContractHash, HashAddr, Key, RuntimeArgs, URef, U256, U512,
+};
 
 #[no_mangle]
 pub extern "C" fn call() {
@@ -59,12 +62,38 @@ fn retrieve_deposit_entry_point_name_args(
 }
 
 fn retrieve_mint_args(src_purse: URef, amount: U256) -> RuntimeArgs {
-    runtime_args! {
+    let mut mint_args = runtime_args! {
         "token_meta_data" => runtime::get_named_arg::<String>("token_metadata"),
         "src_purse" => src_purse,
         "amount" => amount,
         "token_owner" => runtime::get_named_arg::<Key>("token_owner"),
         "nft_contract_package" => runtime::get_named_arg::<Key>("nft_contract_package"),
         "count" => runtime::get_named_arg::<u8>("count"),
+    };
+    // Merkle allowlist proof, only given during allowlisted phases
+    if has_named_arg("proof") {
+        mint_args
+            .insert("proof", runtime::get_named_arg::<Vec<[u8; 32]>>("proof"))
+            .unwrap_or_revert();
+        mint_args
+            .insert("allocation", runtime::get_named_arg::<u8>("allocation"))
+            .unwrap_or_revert();
+    }
+    mint_args
+}
+
+fn has_named_arg(name: &str) -> bool {
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        ext_ffi::casper_get_named_arg_size(
+            name.as_bytes().as_ptr(),
+            name.len(),
+            &mut arg_size as *mut usize,
+        )
+    };
+    match api_error::result_from(ret) {
+        Ok(_) => true,
+        Err(ApiError::MissingArgument) => false,
+        Err(e) => runtime::revert(e),
     }
 }
@@ -4,6 +4,8 @@
 pub const FEE_TOKEN_KEY_NAME: &str = "contract_hash";
 pub const CONTRACT_HASH_KEY_NAME: &str = "contract_hash";
 pub const CONTRACT_OWNER_KEY_NAME: &str = "contract_owner";
+pub const PENDING_OWNER_KEY_NAME: &str = "pending_owner";
+pub const PAUSED_KEY_NAME: &str = "paused";
 pub const REWARD_RATE_KEY_NAME: &str = "reward_rate";
 
 pub const LAST_UPDATE_KEY_NAME: &str = "last_update_time";
@@ -22,8 +24,12 @@ pub const USER_REWARD_PER_TOKEN_PAID_KEY_NAME: &str = "user_reward_per_token_pai
 
 // Entry points
 
-pub const TRANSFER_OWNER_ENTRY_POINT_NAME: &str = "transfer_owner";
+pub const PROPOSE_OWNER_ENTRY_POINT_NAME: &str = "propose_owner";
+pub const ACCEPT_OWNERSHIP_ENTRY_POINT_NAME: &str = "accept_ownership";
+pub const CANCEL_OWNERSHIP_TRANSFER_ENTRY_POINT_NAME: &str = "cancel_ownership_transfer";
 pub const INIT_ENTRY_POINT_NAME: &str = "init";
+pub const PAUSE_ENTRY_POINT_NAME: &str = "pause";
+pub const UNPAUSE_ENTRY_POINT_NAME: &str = "unpause";
 
 // Runtime argument names
 pub const ARG_CONTRACT_HASH: &str = "contract_hash";
@@ -63,6 +69,7 @@ pub const BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
 pub const TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
 /// Name of `mint` entry point.
 pub const MINT_ENTRY_POINT_NAME: &str = "mint";
+pub const NUMBER_OF_MINTED_TOKENS_ENTRY_POINT_NAME: &str = "number_of_minted_tokens";
 /// Name of `approve` entry point.
 pub const APPROVE_ENTRY_POINT_NAME: &str = "approve";
 /// Name of `allowance` entry point.
@@ -17,6 +17,56 @@ fn redeem() -> EntryPoint {
     )
 }
 
+fn propose_owner() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PROPOSE_OWNER_ENTRY_POINT_NAME),
+        vec![Parameter::new(ARG_CONTRACT_OWNER, CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn accept_ownership() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ACCEPT_OWNERSHIP_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn cancel_ownership_transfer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_OWNERSHIP_TRANSFER_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn pause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PAUSE_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+fn unpause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UNPAUSE_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 fn init() -> EntryPoint {
     EntryPoint::new(
         String::from(INIT_ENTRY_POINT_NAME),
@@ -37,6 +87,11 @@ pub(crate) fn default() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
     // entry_points.add_entry_point(transfer_dev());
     entry_points.add_entry_point(redeem());
+    entry_points.add_entry_point(propose_owner());
+    entry_points.add_entry_point(accept_ownership());
+    entry_points.add_entry_point(cancel_ownership_transfer());
+    entry_points.add_entry_point(pause());
+    entry_points.add_entry_point(unpause());
     entry_points.add_entry_point(init());
     entry_points
 }
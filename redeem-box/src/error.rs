@@ -73,6 +73,10 @@ pub enum Error {
     InvalidMintingEnd = 443,
     MintingTimeInvalid = 444,
     OnlyOwner = 445,
+    ContractPaused = 446,
+    InvalidTokenOwner = 447,
+    NoPendingOwner = 448,
+    NotPendingOwner = 449,
 }
 
 impl From<Error> for ApiError {
@@ -22,8 +22,11 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    contracts::NamedKeys, runtime_args, AsymmetricType, ContractPackageHash, HashAddr, Key,
-    PublicKey, RuntimeArgs,
+    contracts::NamedKeys, runtime_args, ContractPackageHash, HashAddr, Key, RuntimeArgs,
+};
+use events::{
+    OwnerTransferred, OwnershipTransferCancelled, OwnershipTransferProposed, Paused, Redeemed,
+    Unpaused,
 };
 
 #[no_mangle]
@@ -40,6 +43,9 @@ pub extern "C" fn init() {
     set_key("box_package_hash", box_package_hash);
     set_key(CONTRACT_OWNER_KEY_NAME, contract_owner);
     set_key("punk_gen1_package_hash", punk_gen1_package_hash);
+    set_key(PAUSED_KEY_NAME, false);
+
+    events::init_events();
 }
 
 #[no_mangle]
@@ -92,6 +98,7 @@ fn call() {
 // mint function of factory
 #[no_mangle]
 pub extern "C" fn redeem() {
+    when_not_paused();
     let token_ids: Vec<u64> = helpers::get_named_arg_with_user_errors(
         "token_ids",
         Error::MissingTokenMetaData,
@@ -101,43 +108,86 @@ pub extern "C" fn redeem() {
 
     let caller = helpers::get_immediate_caller_key();
 
-    // burn it
-    let burner_pubkey =
-        PublicKey::from_hex("020311111111111111111111111111111111111111111111111111111111deadbeef")
-            .unwrap();
-    let burner_account_hash = burner_pubkey.to_account_hash();
-    let burner = Key::from(burner_account_hash);
     let box_package_hash: Key = get_key("box_package_hash").unwrap();
     let punk_gen1_package_hash: Key = get_key("punk_gen1_package_hash").unwrap();
 
-    // burn the nfts
+    // burn the boxes, the caller must have approved this contract as an operator
     for token_id in &token_ids {
-        transfer_from_nft(box_package_hash, caller, burner, *token_id);
+        burn_nft(box_package_hash, caller, *token_id);
     }
 
+    // gen1 token ids are assigned sequentially from the number of minted tokens
+    let minted_before = cep78_number_of_minted_tokens(&punk_gen1_package_hash);
     call_cep78_mint(&punk_gen1_package_hash, caller, token_ids.len() as u64);
+    let minted_after = cep78_number_of_minted_tokens(&punk_gen1_package_hash);
+    let gen1_token_ids: Vec<u64> = (minted_before + 1..=minted_after).collect();
+
+    casper_event_standard::emit(Redeemed::new(caller, token_ids, gen1_token_ids));
 }
 
-fn transfer_from_nft(nft_package_hash: Key, from: Key, to: Key, token_id: u64) {
-    let rt = runtime_args! {
-        "source_key" => from,
-        "target_key" => to,
-        "token_id" => token_id
-    };
+fn burn_nft(nft_package_hash: Key, owner: Key, token_id: u64) {
+    let nft_package_hash = ContractPackageHash::new(nft_package_hash.into_hash().unwrap());
 
-    let _: (String, Key) = runtime::call_versioned_contract(
-        ContractPackageHash::new(nft_package_hash.into_hash().unwrap()),
+    let token_owner: Key = runtime::call_versioned_contract(
+        nft_package_hash,
         None,
-        "transfer",
-        rt,
+        ENTRY_POINT_OWNER_OF,
+        runtime_args! {
+            "token_id" => token_id
+        },
+    );
+    if token_owner != owner {
+        runtime::revert(Error::InvalidTokenOwner);
+    }
+
+    let _: () = runtime::call_versioned_contract(
+        nft_package_hash,
+        None,
+        BURN_ENTRY_POINT_NAME,
+        runtime_args! {
+            "token_id" => token_id
+        },
     );
 }
 
+// first step of an ownership transfer, the new owner has to accept it
 #[no_mangle]
-pub extern "C" fn transfer_owner() {
+pub extern "C" fn propose_owner() {
     only_owner();
-    let new_contract_owner: Key = runtime::get_named_arg(ARG_CONTRACT_OWNER);
-    set_key(CONTRACT_OWNER_KEY_NAME, new_contract_owner);
+    let pending_owner: Key = helpers::get_named_arg_with_user_errors(
+        ARG_CONTRACT_OWNER,
+        Error::MissingContractOwner,
+        Error::InvalidContractOwner,
+    )
+    .unwrap_or_revert();
+    set_key(PENDING_OWNER_KEY_NAME, Some(pending_owner));
+    casper_event_standard::emit(OwnershipTransferProposed::new(
+        owner_internal(),
+        pending_owner,
+    ));
+}
+
+#[no_mangle]
+pub extern "C" fn accept_ownership() {
+    let pending_owner = pending_owner_internal().unwrap_or_revert_with(Error::NoPendingOwner);
+    if pending_owner != helpers::get_verified_caller().unwrap_or_revert() {
+        runtime::revert(Error::NotPendingOwner);
+    }
+    let previous_owner = owner_internal();
+    set_key(CONTRACT_OWNER_KEY_NAME, pending_owner);
+    set_key::<Option<Key>>(PENDING_OWNER_KEY_NAME, None);
+    casper_event_standard::emit(OwnerTransferred::new(previous_owner, pending_owner));
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_ownership_transfer() {
+    only_owner();
+    let pending_owner = pending_owner_internal().unwrap_or_revert_with(Error::NoPendingOwner);
+    set_key::<Option<Key>>(PENDING_OWNER_KEY_NAME, None);
+    casper_event_standard::emit(OwnershipTransferCancelled::new(
+        owner_internal(),
+        pending_owner,
+    ));
 }
 
 fn call_cep78_mint(nft_contract_package: &Key, owner: Key, count: u64) {
@@ -155,6 +205,42 @@ fn call_cep78_mint(nft_contract_package: &Key, owner: Key, count: u64) {
     );
 }
 
+// stop sales until `unpause` is called
+#[no_mangle]
+pub extern "C" fn pause() {
+    only_owner();
+    set_key(PAUSED_KEY_NAME, true);
+    casper_event_standard::emit(Paused::new(
+        helpers::get_verified_caller().unwrap_or_revert(),
+    ));
+}
+
+#[no_mangle]
+pub extern "C" fn unpause() {
+    only_owner();
+    set_key(PAUSED_KEY_NAME, false);
+    casper_event_standard::emit(Unpaused::new(
+        helpers::get_verified_caller().unwrap_or_revert(),
+    ));
+}
+
+fn when_not_paused() {
+    helpers::require(
+        !get_key::<bool>(PAUSED_KEY_NAME).unwrap_or_default(),
+        Error::ContractPaused,
+    );
+}
+
+fn cep78_number_of_minted_tokens(nft_contract_package: &Key) -> u64 {
+    let nft_contract_package_addr: HashAddr = nft_contract_package.into_hash().unwrap_or_revert();
+    runtime::call_versioned_contract(
+        ContractPackageHash::new(nft_contract_package_addr),
+        None,
+        NUMBER_OF_MINTED_TOKENS_ENTRY_POINT_NAME,
+        runtime_args! {},
+    )
+}
+
 pub fn only_owner() {
     helpers::require(
         owner_internal() == helpers::get_verified_caller().unwrap_or_revert(),
@@ -162,6 +248,10 @@ pub fn only_owner() {
     );
 }
 
+pub fn pending_owner_internal() -> Option<Key> {
+    get_key::<Option<Key>>(PENDING_OWNER_KEY_NAME).flatten()
+}
+
 pub fn owner_internal() -> Key {
     let owner_key: Key = helpers::get_stored_value_with_user_errors::<Key>(
         CONTRACT_OWNER_KEY_NAME,
@@ -582,20 +582,24 @@ const CEP78 = class {
             ttl: ttl ? ttl : DEFAULT_TTL,
         });
     }
-    async setParams({ keys, mintingStart, mintingEnd, mintingPrice, paymentAmount, ttl }) {
+    async setPhase({ keys, phaseId, mintingStart, mintingEnd, mintFee, totalBox, maxPerOne, whitelistOnly, paymentAmount, ttl }) {
 
 
         let runtimeArgs = {};
         runtimeArgs = RuntimeArgs.fromMap({
+            phase_id: CLValueBuilder.u64(phaseId),
             minting_start_time: CLValueBuilder.u64(mintingStart),
             minting_end_time: CLValueBuilder.u64(mintingEnd),
-            minting_price: CLValueBuilder.u256(mintingPrice)
+            mint_fee: CLValueBuilder.u256(mintFee),
+            total_box: CLValueBuilder.u64(totalBox),
+            max_per_one: CLValueBuilder.u8(maxPerOne),
+            whitelist_only: CLValueBuilder.bool(whitelistOnly)
         });
 
         console.log(runtimeArgs)
 
         return await this.contractClient.contractCall({
-            entryPoint: "update_mint_params",
+            entryPoint: "set_phase",
             keys: keys,
             paymentAmount: paymentAmount ? paymentAmount : "10000000000",
             runtimeArgs,
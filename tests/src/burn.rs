@@ -5,25 +5,27 @@ use casper_engine_test_support::{
 use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs};
 use contract::{
     constants::{
-        ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_OPERATOR, ARG_TOKEN_ID,
-        ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, BURNT_TOKENS, BURN_MODE, ENTRY_POINT_BURN,
-        ENTRY_POINT_MINT, ENTRY_POINT_SET_APPROVALL_FOR_ALL, TOKEN_COUNT,
+        ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_OPERATOR, ARG_SPENDER, ARG_TOKEN_ID,
+        ARG_TOKEN_META_DATA, ARG_TOKEN_META_DATAS, ARG_TOKEN_OWNER, ARG_TOKEN_OWNERS, BURNT_TOKENS,
+        BURN_MODE, ENTRY_POINT_APPROVE, ENTRY_POINT_BATCH_MINT, ENTRY_POINT_BURN, ENTRY_POINT_MINT,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL, NUMBER_OF_BURNT_TOKENS, TOKEN_COUNT,
     },
     events::events_ces::Burn,
-    modalities::{TokenIdentifier, EventsMode},
+    modalities::{EventsMode, TokenIdentifier},
 };
 
 use crate::utility::{
     constants::{
         ACCOUNT_USER_1, ARG_NFT_CONTRACT_HASH, ARG_REVERSE_LOOKUP, CONTRACT_NAME,
-        MINTING_CONTRACT_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION,
-        TEST_PRETTY_721_META_DATA,
+        INSTALL_EVENTS_COUNT, MINTING_CONTRACT_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM,
+        NFT_TEST_COLLECTION, SUPPLY_SESSION_WASM, TEST_PRETTY_721_META_DATA,
     },
     installer_request_builder::{
         BurnMode, InstallerRequestBuilder, OwnerReverseLookupMode, OwnershipMode,
     },
     support::{
-        self, get_dictionary_value_from_key, get_minting_contract_hash, get_nft_contract_hash, get_minting_contract_package,
+        self, get_dictionary_value_from_key, get_minting_contract_hash,
+        get_minting_contract_package, get_nft_contract_hash,
     },
 };
 
@@ -134,9 +136,17 @@ fn should_burn_minted_token(reporting: OwnerReverseLookupMode) {
     let expected_balance = 0u64;
     assert_eq!(actual_balance, expected_balance);
 
+    let actual_number_of_burnt_tokens: u64 = support::query_stored_value(
+        &builder,
+        *nft_contract_key,
+        vec![NUMBER_OF_BURNT_TOKENS.to_string()],
+    );
+    assert_eq!(actual_number_of_burnt_tokens, 1u64);
+
     // Expect Burn event.
     let expected_event = Burn::new(token_owner, TokenIdentifier::Index(1));
-    let actual_event: Burn = support::get_event(&builder, nft_contract_key, 1);
+    let actual_event: Burn =
+        support::get_event(&builder, nft_contract_key, INSTALL_EVENTS_COUNT + 1);
     assert_eq!(actual_event, expected_event, "Expected Burn event.");
 }
 
@@ -489,6 +499,127 @@ fn should_allow_contract_to_burn_token() {
     assert_eq!(updated_token_balance, 0u64)
 }
 
+#[test]
+fn should_allow_operator_to_burn_token() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let operator = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let mint_session_call = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+
+    builder.exec(mint_session_call).expect_success().commit();
+
+    let token_id = 1u64;
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        operator,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    support::assert_expected_error(error, 6u16, "should disallow burning before approval");
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_APPROVE,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+            ARG_SPENDER => Key::Account(operator)
+        },
+    )
+    .build();
+
+    builder.exec(approve_request).expect_success().commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        operator,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    support::assert_expected_error(error, 6u16, "should disallow burning with a token approval");
+
+    let approve_all_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL,
+        runtime_args! {
+            ARG_APPROVE_ALL => true,
+            ARG_OPERATOR => Key::Account(operator)
+        },
+    )
+    .build();
+
+    builder.exec(approve_all_request).expect_success().commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        operator,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => token_id,
+        },
+    )
+    .build();
+
+    builder.exec(burn_request).expect_success().commit();
+
+    //This will error of token is not registered as burnt.
+    support::get_dictionary_value_from_key::<()>(
+        &builder,
+        &nft_contract_key,
+        BURNT_TOKENS,
+        &token_id.to_string(),
+    );
+
+    let actual_balance = support::get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        TOKEN_COUNT,
+        &DEFAULT_ACCOUNT_ADDR.clone().to_string(),
+    );
+
+    assert_eq!(actual_balance, 0u64);
+}
+
 #[test]
 fn should_not_burn_in_non_burn_mode() {
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -605,4 +736,72 @@ fn should_let_approve_all_with_burnt_tokens() {
     .build();
 
     builder.exec(approve_all_request).expect_success().commit();
-}
\ No newline at end of file
+}
+fn get_supply(
+    builder: &mut InMemoryWasmTestBuilder,
+    nft_contract_key: Key,
+    entry_point: &str,
+) -> u64 {
+    support::call_session_code_with_ret(
+        builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_key,
+        runtime_args! {
+            "entry_point" => entry_point.to_string(),
+        },
+        SUPPLY_SESSION_WASM,
+        entry_point,
+    )
+}
+
+#[test]
+fn should_report_the_supply_after_burning() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BATCH_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNERS => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR); 3],
+            ARG_TOKEN_META_DATAS => vec![None::<String>; 3],
+        },
+    )
+    .build();
+    builder.exec(batch_mint_request).expect_success().commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => 2u64,
+        },
+    )
+    .build();
+    builder.exec(burn_request).expect_success().commit();
+
+    // 3 minted tokens less the burnt one, out of 10
+    assert_eq!(
+        get_supply(&mut builder, nft_contract_key, "total_supply"),
+        2u64
+    );
+    assert_eq!(
+        get_supply(&mut builder, nft_contract_key, "circulating_supply"),
+        2u64
+    );
+    assert_eq!(
+        get_supply(&mut builder, nft_contract_key, "remaining_mintable"),
+        7u64
+    );
+}
@@ -11,6 +11,14 @@ mod installer;
 #[cfg(test)]
 mod mint;
 // A collection of tests that are focused
+// around granting and revoking roles.
+#[cfg(test)]
+mod roles;
+// A collection of tests that are focused
+// around royalties.
+#[cfg(test)]
+mod royalty;
+// A collection of tests that are focused
 // around toggling control variables in the contract.
 #[cfg(test)]
 mod set_variables;
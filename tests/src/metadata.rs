@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
     DEFAULT_RUN_GENESIS_REQUEST,
@@ -5,8 +7,9 @@ use casper_engine_test_support::{
 use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs};
 use contract::constants::{
     ARG_COLLECTION_NAME, ARG_CONTRACT_WHITELIST, ARG_TOKEN_HASH, ARG_TOKEN_ID, ARG_TOKEN_META_DATA,
-    ARG_TOKEN_OWNER, ENTRY_POINT_METADATA, ENTRY_POINT_MINT, ENTRY_POINT_SET_TOKEN_METADATA,
-    METADATA_CEP78, TOKEN_OWNERS,
+    ARG_TOKEN_META_DATAS, ARG_TOKEN_OWNER, ARG_TOKEN_OWNERS, ENTRY_POINT_BATCH_MINT,
+    ENTRY_POINT_METADATA, ENTRY_POINT_MINT, ENTRY_POINT_SET_TOKEN_METADATA, METADATA_CEP78,
+    TOKEN_OWNERS,
 };
 
 use crate::utility::{
@@ -255,3 +258,160 @@ fn get_schema() {
         serde_json::to_string_pretty(&*TEST_CUSTOM_METADATA).unwrap()
     )
 }
+
+const ENTRY_POINT_SET_METADATA_OFFSET: &str = "set_metadata_offset";
+const ARG_METADATA_SEED: &str = "metadata_seed";
+const METADATA_SEED: &str = "gen1-seed";
+// blake2b hash of METADATA_SEED
+const METADATA_SEED_HASH: &str = "9b979728ba6eee6942076da527e860963c40bb62bdde1ff24b0757f9cc4a4d8d";
+
+#[test]
+fn should_shuffle_every_token_onto_a_distinct_metadata_index() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let total_token_supply = 7u64;
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(total_token_supply)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_metadata_seed_hash(METADATA_SEED_HASH.to_string())
+        .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let set_metadata_offset_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_METADATA_OFFSET,
+        runtime_args! {
+            ARG_METADATA_SEED => METADATA_SEED.to_string(),
+        },
+    )
+    .build();
+    builder
+        .exec(set_metadata_offset_request)
+        .expect_success()
+        .commit();
+
+    // Mint the whole collection with the metadata template.
+    let count = total_token_supply as usize;
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BATCH_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNERS => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR); count],
+            ARG_TOKEN_META_DATAS => vec![None::<String>; count],
+        },
+    )
+    .build();
+    builder.exec(batch_mint_request).expect_success().commit();
+
+    let metadata_indices: Vec<u64> = (1..=total_token_supply)
+        .map(|token_id| {
+            let token_metadata: String = support::get_dictionary_value_from_key(
+                &builder,
+                &nft_contract_key,
+                METADATA_CEP78,
+                &token_id.to_string(),
+            );
+            let token_metadata: serde_json::Value = serde_json::from_str(&token_metadata).unwrap();
+            token_metadata["token_uri"]
+                .as_str()
+                .and_then(|token_uri| token_uri.rsplit('/').next())
+                .and_then(|metadata_index| metadata_index.parse().ok())
+                .expect("token_uri must end with the metadata index")
+        })
+        .collect();
+
+    // The same range as without shuffling, with every index taken exactly once.
+    let unique_indices: BTreeSet<u64> = metadata_indices.iter().copied().collect();
+    assert_eq!(metadata_indices.len(), unique_indices.len());
+    assert_eq!(unique_indices, (1..=total_token_supply).collect());
+
+    // Token ids start from 1, so there is no metadata index for id 0.
+    let update_metadata_url_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_UPDATE_METADATA_URL_FOR_TOKENS,
+        runtime_args! {
+            ARG_TOKEN_IDS => vec![0u64],
+        },
+    )
+    .build();
+    builder.exec(update_metadata_url_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 28u16, "should reject token id 0 once shuffled");
+}
+
+const ENTRY_POINT_UPDATE_METADATA_URL_FOR_TOKENS: &str = "update_metadata_url_for_tokens";
+const ARG_TOKEN_IDS: &str = "token_ids";
+
+const ENTRY_POINT_UPDATE_BASE_METADATA: &str = "update_base_metadata";
+const ENTRY_POINT_REVEAL: &str = "reveal";
+const ARG_BASE_METADATA_URL: &str = "base_metadata_url";
+const ARG_BASE_URI: &str = "base_uri";
+const REVEAL_BASE_URI: &str = "ipfs://gen1/";
+// blake2b hash of REVEAL_BASE_URI
+const REVEAL_BASE_URI_HASH: &str =
+    "12994fe8f697d3f4593e5cfd030a9cace2013d553a72ed9ddb803fafca522e8a";
+
+#[test]
+fn should_only_write_committed_base_uri_through_reveal() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_reveal_base_uri_hash(REVEAL_BASE_URI_HASH.to_string())
+        .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let update_base_metadata_request = || {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            nft_contract_hash,
+            ENTRY_POINT_UPDATE_BASE_METADATA,
+            runtime_args! {
+                ARG_BASE_METADATA_URL => "https://example.com/".to_string(),
+            },
+        )
+        .build()
+    };
+
+    builder
+        .exec(update_base_metadata_request())
+        .expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 225u16, "should not update a committed base uri");
+
+    let reveal_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REVEAL,
+        runtime_args! {
+            ARG_BASE_URI => REVEAL_BASE_URI.to_string(),
+        },
+    )
+    .build();
+    builder.exec(reveal_request).expect_success().commit();
+
+    builder
+        .exec(update_base_metadata_request())
+        .expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 225u16, "should not update a revealed base uri");
+
+    let actual_base_metadata_url: String = query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![ARG_BASE_METADATA_URL.to_string()],
+    );
+    assert_eq!(actual_base_metadata_url, REVEAL_BASE_URI);
+}
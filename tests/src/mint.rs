@@ -1,10 +1,11 @@
 use contract::{
     constants::{
         ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_MINTING_MODE, ARG_OPERATOR, ARG_SOURCE_KEY,
-        ARG_TARGET_KEY, ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ENTRY_POINT_APPROVE,
-        ENTRY_POINT_MINT, ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_SET_APPROVALL_FOR_ALL,
-        METADATA_CEP78, NUMBER_OF_MINTED_TOKENS, PAGE_TABLE, TOKEN_COUNT, TOKEN_ISSUERS,
-        TOKEN_OWNERS, ARG_SPENDER
+        ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_META_DATAS,
+        ARG_TOKEN_OWNER, ARG_TOKEN_OWNERS, ENTRY_POINT_APPROVE, ENTRY_POINT_BATCH_MINT,
+        ENTRY_POINT_BURN, ENTRY_POINT_MINT, ENTRY_POINT_REGISTER_OWNER,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL, INDEX_BY_HASH, METADATA_CEP78, NUMBER_OF_MINTED_TOKENS,
+        PAGE_TABLE, TOKEN_COUNT, TOKEN_ISSUERS, TOKEN_OWNERS,
     },
     events::events_ces::{ApprovalForAll, Mint, RevokedForAll},
     modalities::TokenIdentifier,
@@ -16,19 +17,19 @@ use casper_engine_test_support::{
     DEFAULT_RUN_GENESIS_REQUEST,
 };
 use casper_execution_engine::storage::global_state::in_memory::InMemoryGlobalState;
-use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs};
+use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs};
 
 use crate::utility::{
     constants::{
         ACCOUNT_USER_1, ACCOUNT_USER_2, ARG_IS_HASH_IDENTIFIER_MODE, ARG_KEY_NAME,
         ARG_NFT_CONTRACT_HASH, BALANCE_OF_SESSION_WASM, CONTRACT_NAME, GET_APPROVED_WASM,
-        IS_APPROVED_FOR_ALL_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION,
-        OWNER_OF_SESSION_WASM, TEST_PRETTY_721_META_DATA, TEST_PRETTY_CEP78_METADATA,
-        TRANSFER_SESSION_WASM,
+        INSTALL_EVENTS_COUNT, IS_APPROVED_FOR_ALL_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM,
+        NFT_TEST_COLLECTION, OWNER_OF_SESSION_WASM, TEST_PRETTY_721_META_DATA,
+        TEST_PRETTY_CEP78_METADATA, TOKENS_OF_OWNER_SESSION_WASM, TRANSFER_SESSION_WASM,
     },
     installer_request_builder::{
-        InstallerRequestBuilder, MetadataMutability, MintingMode, NFTIdentifierMode,
-        NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode,
+        InstallerRequestBuilder, MetadataMutability, MintingMode, NFTHolderMode, NFTIdentifierMode,
+        NFTMetadataKind, OwnerReverseLookupMode, OwnershipMode, WhitelistMode,
     },
     support::{
         self, assert_expected_error, call_session_code_with_ret, create_funded_dummy_account,
@@ -230,7 +231,7 @@ fn should_mint() {
         TokenIdentifier::Index(1),
         TEST_PRETTY_CEP78_METADATA.to_string(),
     );
-    let _actual_event: Mint = support::get_event(&builder, &nft_contract_key, 0);
+    let _actual_event: Mint = support::get_event(&builder, &nft_contract_key, INSTALL_EVENTS_COUNT);
 }
 
 #[test]
@@ -387,6 +388,132 @@ fn mint_should_increment_number_of_minted_tokens_by_one_and_add_public_key_to_to
     builder.exec(mint_session_call).expect_success().commit();
 }
 
+#[test]
+fn should_batch_mint_to_several_owners_within_supply() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let install_request_builder =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(3u64)
+            .with_ownership_mode(OwnershipMode::Transferable);
+    builder
+        .exec(install_request_builder.build())
+        .expect_success()
+        .commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_receiver = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BATCH_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNERS => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR), Key::Account(token_receiver)],
+            ARG_TOKEN_META_DATAS => vec![None, Some(TEST_PRETTY_CEP78_METADATA.to_string())],
+        },
+    )
+    .build();
+    builder.exec(batch_mint_request).expect_success().commit();
+
+    let number_of_minted_tokens: u64 = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![NUMBER_OF_MINTED_TOKENS.to_string()],
+    );
+    assert_eq!(number_of_minted_tokens, 2u64);
+
+    let second_token_owner = support::get_dictionary_value_from_key::<Key>(
+        &builder,
+        &nft_contract_key,
+        TOKEN_OWNERS,
+        &2u64.to_string(),
+    );
+    assert_eq!(second_token_owner, Key::Account(token_receiver));
+
+    // Only one token is left, so a batch of two is rejected as a whole.
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BATCH_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNERS => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR), Key::Account(token_receiver)],
+            ARG_TOKEN_META_DATAS => vec![None::<String>, None],
+        },
+    )
+    .build();
+    builder.exec(batch_mint_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 32u16, "should not mint past the total token supply");
+}
+
+#[test]
+fn should_batch_mint_in_hash_identifier_mode() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let install_request_builder =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(10u64)
+            .with_ownership_mode(OwnershipMode::Transferable)
+            .with_identifier_mode(NFTIdentifierMode::Hash)
+            .with_metadata_mutability(MetadataMutability::Immutable)
+            .with_nft_metadata_kind(NFTMetadataKind::CEP78);
+    builder
+        .exec(install_request_builder.build())
+        .expect_success()
+        .commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BATCH_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNERS => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)],
+            ARG_TOKEN_META_DATAS => vec![Some(TEST_PRETTY_CEP78_METADATA.to_string())],
+        },
+    )
+    .build();
+    builder.exec(batch_mint_request).expect_success().commit();
+
+    let token_hash =
+        base16::encode_lower(&support::create_blake2b_hash(TEST_PRETTY_CEP78_METADATA));
+    let token_owner = support::get_dictionary_value_from_key::<Key>(
+        &builder,
+        &nft_contract_key,
+        TOKEN_OWNERS,
+        &token_hash,
+    );
+    assert_eq!(token_owner, Key::Account(*DEFAULT_ACCOUNT_ADDR));
+
+    let token_index = support::get_dictionary_value_from_key::<u64>(
+        &builder,
+        &nft_contract_key,
+        INDEX_BY_HASH,
+        &token_hash,
+    );
+    assert_eq!(token_index, 1u64);
+
+    // The same metadata would produce the same hash, so it can't be minted twice.
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BATCH_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNERS => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR)],
+            ARG_TOKEN_META_DATAS => vec![Some(TEST_PRETTY_CEP78_METADATA.to_string())],
+        },
+    )
+    .build();
+    builder.exec(batch_mint_request).expect_failure();
+}
+
 #[test]
 fn should_set_meta_data() {
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -694,7 +821,8 @@ fn should_set_approval_for_all() {
 
     // Expect ApprovalForAll event.
     let expected_event = ApprovalForAll::new(owner_key, operator_key);
-    let actual_event: ApprovalForAll = support::get_event(&builder, &nft_contract_key, 1);
+    let actual_event: ApprovalForAll =
+        support::get_event(&builder, &nft_contract_key, INSTALL_EVENTS_COUNT + 1);
     assert_eq!(
         actual_event, expected_event,
         "Expected ApprovalForAll event."
@@ -850,7 +978,8 @@ fn should_revoke_approval_for_all() {
 
     // Expect ApprovalForAll event.
     let expected_event = ApprovalForAll::new(owner_key, operator_key);
-    let actual_event: ApprovalForAll = support::get_event(&builder, &nft_contract_key, 1);
+    let actual_event: ApprovalForAll =
+        support::get_event(&builder, &nft_contract_key, INSTALL_EVENTS_COUNT + 1);
     assert_eq!(
         actual_event, expected_event,
         "Expected ApprovalForAll event."
@@ -888,7 +1017,8 @@ fn should_revoke_approval_for_all() {
 
     // Expect RevokedForAll event.
     let expected_event = RevokedForAll::new(owner_key, operator_key);
-    let actual_event: RevokedForAll = support::get_event(&builder, &nft_contract_key, 2);
+    let actual_event: RevokedForAll =
+        support::get_event(&builder, &nft_contract_key, INSTALL_EVENTS_COUNT + 2);
     assert_eq!(
         actual_event, expected_event,
         "Expected RevokedForAll event."
@@ -1256,3 +1386,304 @@ fn should_approve_all_with_flat_gas_cost() {
         second_set_approve_for_all_gas_cost
     )
 }
+
+fn should_validate_token_owner_against_holder_mode(
+    holder_mode: NFTHolderMode,
+    whitelist_mode: WhitelistMode,
+    token_owner_key: Key,
+    expected_error: Option<u16>,
+) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let install_request_builder =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(10u64)
+            .with_ownership_mode(OwnershipMode::Transferable)
+            .with_holder_mode(holder_mode)
+            .with_whitelist_mode(whitelist_mode)
+            .with_contract_whitelist(vec![ContractHash::new([7u8; 32])]);
+    builder
+        .exec(install_request_builder.build())
+        .expect_success()
+        .commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BATCH_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNERS => vec![token_owner_key],
+            ARG_TOKEN_META_DATAS => vec![None::<String>],
+        },
+    )
+    .build();
+
+    match expected_error {
+        None => {
+            builder.exec(batch_mint_request).expect_success().commit();
+
+            let actual_token_owner = support::get_dictionary_value_from_key::<Key>(
+                &builder,
+                &nft_contract_key,
+                TOKEN_OWNERS,
+                &1u64.to_string(),
+            );
+            assert_eq!(actual_token_owner, token_owner_key);
+        }
+        Some(expected_error) => {
+            builder.exec(batch_mint_request).expect_failure();
+
+            let error = builder.get_error().expect("must have error");
+            assert_expected_error(
+                error,
+                expected_error,
+                "should not mint to an owner disallowed by the holder mode",
+            );
+
+            let number_of_minted_tokens: u64 = support::query_stored_value(
+                &builder,
+                nft_contract_key,
+                vec![NUMBER_OF_MINTED_TOKENS.to_string()],
+            );
+            assert_eq!(number_of_minted_tokens, 0u64);
+        }
+    }
+}
+
+#[test]
+fn should_mint_to_account_in_accounts_holder_mode() {
+    should_validate_token_owner_against_holder_mode(
+        NFTHolderMode::Accounts,
+        WhitelistMode::Unlocked,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        None,
+    );
+}
+
+#[test]
+fn should_not_mint_to_contract_in_accounts_holder_mode() {
+    should_validate_token_owner_against_holder_mode(
+        NFTHolderMode::Accounts,
+        WhitelistMode::Unlocked,
+        Key::Hash([8u8; 32]),
+        Some(76u16),
+    );
+}
+
+#[test]
+fn should_not_mint_to_account_in_contracts_holder_mode() {
+    should_validate_token_owner_against_holder_mode(
+        NFTHolderMode::Contracts,
+        WhitelistMode::Unlocked,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Some(76u16),
+    );
+}
+
+#[test]
+fn should_mint_to_contract_in_contracts_holder_mode() {
+    should_validate_token_owner_against_holder_mode(
+        NFTHolderMode::Contracts,
+        WhitelistMode::Unlocked,
+        Key::Hash([8u8; 32]),
+        None,
+    );
+}
+
+#[test]
+fn should_mint_to_account_in_mixed_holder_mode() {
+    should_validate_token_owner_against_holder_mode(
+        NFTHolderMode::Mixed,
+        WhitelistMode::Unlocked,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        None,
+    );
+}
+
+#[test]
+fn should_mint_to_contract_in_mixed_holder_mode() {
+    should_validate_token_owner_against_holder_mode(
+        NFTHolderMode::Mixed,
+        WhitelistMode::Unlocked,
+        Key::Hash([8u8; 32]),
+        None,
+    );
+}
+
+#[test]
+fn should_mint_to_whitelisted_contract_with_locked_whitelist() {
+    should_validate_token_owner_against_holder_mode(
+        NFTHolderMode::Contracts,
+        WhitelistMode::Locked,
+        Key::Hash([7u8; 32]),
+        None,
+    );
+}
+
+#[test]
+fn should_not_mint_to_unlisted_contract_with_locked_whitelist() {
+    should_validate_token_owner_against_holder_mode(
+        NFTHolderMode::Mixed,
+        WhitelistMode::Locked,
+        Key::Hash([8u8; 32]),
+        Some(81u16),
+    );
+}
+
+#[test]
+fn should_mint_to_account_in_mixed_holder_mode_with_locked_whitelist() {
+    should_validate_token_owner_against_holder_mode(
+        NFTHolderMode::Mixed,
+        WhitelistMode::Locked,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        None,
+    );
+}
+
+fn batch_mint_to_installer(
+    builder: &mut WasmTestBuilder<InMemoryGlobalState>,
+    nft_contract_hash: ContractHash,
+    count: usize,
+) -> &mut WasmTestBuilder<InMemoryGlobalState> {
+    let batch_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BATCH_MINT,
+        runtime_args! {
+            ARG_TOKEN_OWNERS => vec![Key::Account(*DEFAULT_ACCOUNT_ADDR); count],
+            ARG_TOKEN_META_DATAS => vec![None::<String>; count],
+        },
+    )
+    .build();
+    builder.exec(batch_mint_request)
+}
+
+#[test]
+fn should_mint_from_the_bucket_bound_to_the_minter() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let install_request_builder =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(4u64)
+            .with_ownership_mode(OwnershipMode::Transferable)
+            .with_team_reserve(1u64)
+            .with_redeem_reserve(1u64);
+    builder
+        .exec(install_request_builder.build())
+        .expect_success()
+        .commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    // Unbound minters mint from the public sale, which only holds what isn't reserved.
+    batch_mint_to_installer(&mut builder, nft_contract_hash, 3).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 212u16, "should not mint past the public sale bucket");
+
+    batch_mint_to_installer(&mut builder, nft_contract_hash, 2)
+        .expect_success()
+        .commit();
+
+    let set_minter_bucket_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        "set_minter_bucket",
+        runtime_args! {
+            "account" => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            "bucket" => "team".to_string(),
+        },
+    )
+    .build();
+    builder
+        .exec(set_minter_bucket_request)
+        .expect_success()
+        .commit();
+
+    batch_mint_to_installer(&mut builder, nft_contract_hash, 1)
+        .expect_success()
+        .commit();
+
+    batch_mint_to_installer(&mut builder, nft_contract_hash, 1).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 212u16, "should not mint past the team reserve");
+
+    for (bucket, expected_minted) in [("team", 1u64), ("public", 2u64), ("redeem", 0u64)] {
+        let actual_minted: u64 = support::get_dictionary_value_from_key(
+            &builder,
+            &nft_contract_key,
+            "bucket_minted",
+            bucket,
+        );
+        assert_eq!(actual_minted, expected_minted);
+    }
+}
+
+#[test]
+fn should_page_through_tokens_of_owner() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let install_request_builder =
+        InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+            .with_total_token_supply(5u64)
+            .with_ownership_mode(OwnershipMode::Transferable);
+    builder
+        .exec(install_request_builder.build())
+        .expect_success()
+        .commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    batch_mint_to_installer(&mut builder, nft_contract_hash, 5)
+        .expect_success()
+        .commit();
+
+    let burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BURN,
+        runtime_args! {
+            ARG_TOKEN_ID => 2u64,
+        },
+    )
+    .build();
+    builder.exec(burn_request).expect_success().commit();
+
+    // The burnt token is skipped, so the first page ends on the fourth token.
+    let (token_ids, next_page): (Vec<String>, Option<u64>) = call_session_code_with_ret(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_key,
+        runtime_args! {
+            "owner" => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            "page" => 0u64,
+            "page_size" => 2u64,
+        },
+        TOKENS_OF_OWNER_SESSION_WASM,
+        ARG_KEY_NAME,
+    );
+    assert_eq!(token_ids, vec!["1".to_string(), "3".to_string()]);
+    assert_eq!(next_page, Some(4u64));
+
+    let (token_ids, next_page): (Vec<String>, Option<u64>) = call_session_code_with_ret(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_key,
+        runtime_args! {
+            "owner" => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            "page" => 4u64,
+            "page_size" => 2u64,
+        },
+        TOKENS_OF_OWNER_SESSION_WASM,
+        ARG_KEY_NAME,
+    );
+    assert_eq!(token_ids, vec!["4".to_string(), "5".to_string()]);
+    assert_eq!(next_page, None);
+}
@@ -0,0 +1,285 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    DEFAULT_RUN_GENESIS_REQUEST,
+};
+use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs};
+use contract::{
+    constants::{ARG_COLLECTION_NAME, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER},
+    events::events_ces::{RoleGranted, RoleRevoked},
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_USER_1, ARG_NFT_CONTRACT_HASH, INSTALL_EVENTS_COUNT, MINT_SESSION_WASM,
+        NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, TEST_PRETTY_721_META_DATA,
+    },
+    installer_request_builder::{InstallerRequestBuilder, OwnerReverseLookupMode, OwnershipMode},
+    support::{self, get_nft_contract_hash},
+};
+
+const ENTRY_POINT_GRANT_ROLE: &str = "grant_role";
+const ENTRY_POINT_REVOKE_ROLE: &str = "revoke_role";
+const ENTRY_POINT_SET_TRANSFER_ENABLED: &str = "set_transfer_enabled";
+const ENTRY_POINT_PROPOSE_OWNER: &str = "propose_owner";
+const ENTRY_POINT_ACCEPT_OWNERSHIP: &str = "accept_ownership";
+const ARG_THE_CONTRACT_OWNER: &str = "the_contract_owner";
+const ARG_ROLE: &str = "role";
+const ARG_ACCOUNT: &str = "account";
+const ADMIN_ROLE: &str = "ADMIN";
+const MINTER_ROLE: &str = "MINTER";
+
+fn mint_as(builder: &mut InMemoryWasmTestBuilder, minter: AccountHash, nft_contract_key: Key) {
+    let mint_session_call = ExecuteRequestBuilder::standard(
+        minter,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_OWNER => Key::Account(minter),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+    builder.exec(mint_session_call);
+}
+
+fn set_role(
+    builder: &mut InMemoryWasmTestBuilder,
+    nft_contract_hash: ContractHash,
+    entry_point: &str,
+    account: AccountHash,
+) {
+    let role_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        entry_point,
+        runtime_args! {
+            ARG_ROLE => MINTER_ROLE.to_string(),
+            ARG_ACCOUNT => Key::Account(account)
+        },
+    )
+    .build();
+    builder.exec(role_request).expect_success().commit();
+}
+
+#[test]
+fn should_only_allow_minter_role_to_mint() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_reporting_mode(OwnerReverseLookupMode::Complete)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+
+    // The installer is granted every role at install.
+    let installer = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    for (index, role) in ["ADMIN", "MINTER", "METADATA_EDITOR", "PAUSER"]
+        .into_iter()
+        .enumerate()
+    {
+        let expected_event = RoleGranted::new(role.to_string(), installer, installer);
+        let actual_event: RoleGranted =
+            support::get_event(&builder, &nft_contract_key, index as u32);
+        assert_eq!(actual_event, expected_event, "Expected RoleGranted event.");
+    }
+
+    let minter = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    mint_as(&mut builder, minter, nft_contract_key);
+    builder.expect_failure();
+    let error = builder.get_error().expect("must have error");
+    support::assert_expected_error(error, 182u16, "should disallow minting without the role");
+
+    set_role(
+        &mut builder,
+        nft_contract_hash,
+        ENTRY_POINT_GRANT_ROLE,
+        minter,
+    );
+    mint_as(&mut builder, minter, nft_contract_key);
+    builder.expect_success().commit();
+
+    set_role(
+        &mut builder,
+        nft_contract_hash,
+        ENTRY_POINT_REVOKE_ROLE,
+        minter,
+    );
+    mint_as(&mut builder, minter, nft_contract_key);
+    builder.expect_failure();
+    let error = builder.get_error().expect("must have error");
+    support::assert_expected_error(error, 182u16, "should disallow minting once revoked");
+}
+
+#[test]
+fn should_only_allow_admin_to_grant_roles() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let account = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let grant_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account,
+        nft_contract_hash,
+        ENTRY_POINT_GRANT_ROLE,
+        runtime_args! {
+            ARG_ROLE => MINTER_ROLE.to_string(),
+            ARG_ACCOUNT => Key::Account(account)
+        },
+    )
+    .build();
+
+    builder.exec(grant_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+    support::assert_expected_error(error, 182u16, "should disallow granting without admin");
+}
+
+#[test]
+fn should_only_allow_admin_to_toggle_transfers() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let account = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let enable_transfer_request = |account: AccountHash| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            account,
+            nft_contract_hash,
+            ENTRY_POINT_SET_TRANSFER_ENABLED,
+            runtime_args! {
+                "enable_transfer" => true,
+            },
+        )
+        .build()
+    };
+
+    builder
+        .exec(enable_transfer_request(account))
+        .expect_failure();
+    let error = builder.get_error().expect("must have error");
+    support::assert_expected_error(error, 182u16, "should disallow toggling without admin");
+
+    let grant_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_GRANT_ROLE,
+        runtime_args! {
+            ARG_ROLE => ADMIN_ROLE.to_string(),
+            ARG_ACCOUNT => Key::Account(account)
+        },
+    )
+    .build();
+    builder.exec(grant_request).expect_success().commit();
+
+    builder
+        .exec(enable_transfer_request(account))
+        .expect_success()
+        .commit();
+}
+
+#[test]
+fn should_hand_the_admin_role_over_with_ownership() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(100u64)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let new_owner = support::create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let propose_owner_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_PROPOSE_OWNER,
+        runtime_args! {
+            ARG_THE_CONTRACT_OWNER => Key::Account(new_owner)
+        },
+    )
+    .build();
+    builder
+        .exec(propose_owner_request)
+        .expect_success()
+        .commit();
+
+    let accept_ownership_request = ExecuteRequestBuilder::contract_call_by_hash(
+        new_owner,
+        nft_contract_hash,
+        ENTRY_POINT_ACCEPT_OWNERSHIP,
+        runtime_args! {},
+    )
+    .build();
+    builder
+        .exec(accept_ownership_request)
+        .expect_success()
+        .commit();
+
+    // The proposal is the first event after install, the role hand over follows it.
+    let previous_owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let expected_event = RoleGranted::new(
+        ADMIN_ROLE.to_string(),
+        Key::Account(new_owner),
+        Key::Account(new_owner),
+    );
+    let actual_event: RoleGranted =
+        support::get_event(&builder, &nft_contract_key, INSTALL_EVENTS_COUNT + 1);
+    assert_eq!(actual_event, expected_event, "Expected RoleGranted event.");
+
+    let expected_event = RoleRevoked::new(
+        ADMIN_ROLE.to_string(),
+        previous_owner,
+        Key::Account(new_owner),
+    );
+    let actual_event: RoleRevoked =
+        support::get_event(&builder, &nft_contract_key, INSTALL_EVENTS_COUNT + 2);
+    assert_eq!(actual_event, expected_event, "Expected RoleRevoked event.");
+
+    let grant_request = |sender: AccountHash| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            sender,
+            nft_contract_hash,
+            ENTRY_POINT_GRANT_ROLE,
+            runtime_args! {
+                ARG_ROLE => MINTER_ROLE.to_string(),
+                ARG_ACCOUNT => Key::Account(new_owner)
+            },
+        )
+        .build()
+    };
+
+    builder
+        .exec(grant_request(new_owner))
+        .expect_success()
+        .commit();
+
+    builder
+        .exec(grant_request(*DEFAULT_ACCOUNT_ADDR))
+        .expect_failure();
+    let error = builder.get_error().expect("must have error");
+    support::assert_expected_error(error, 182u16, "should disallow granting as the old owner");
+}
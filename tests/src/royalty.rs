@@ -0,0 +1,300 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    DEFAULT_RUN_GENESIS_REQUEST,
+};
+use casper_types::{account::AccountHash, runtime_args, ContractHash, Key, RuntimeArgs, U512};
+use contract::{
+    constants::{ARG_COLLECTION_NAME, ARG_TOKEN_ID, ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER},
+    events::events_ces::RoyaltyUpdated,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_USER_1, ARG_IS_HASH_IDENTIFIER_MODE, ARG_KEY_NAME, ARG_NFT_CONTRACT_HASH,
+        MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, ROYALTY_INFO_SESSION_WASM,
+        TEST_PRETTY_721_META_DATA,
+    },
+    installer_request_builder::{InstallerRequestBuilder, OwnershipMode},
+    support::{self, assert_expected_error, create_funded_dummy_account, get_nft_contract_hash},
+};
+
+const ENTRY_POINT_SET_ROYALTY: &str = "set_royalty";
+const ENTRY_POINT_SET_TOKEN_ROYALTY: &str = "set_token_royalty";
+const ENTRY_POINT_CLEAR_TOKEN_ROYALTY: &str = "clear_token_royalty";
+const ARG_ROYALTY_RECIPIENT: &str = "royalty_recipient";
+const ARG_ROYALTY_BASIS_POINTS: &str = "royalty_basis_points";
+const ARG_SALE_PRICE: &str = "sale_price";
+const ROYALTY_RECIPIENT: &str = "royalty_recipient";
+const ROYALTY_BASIS_POINTS: &str = "royalty_basis_points";
+const TOKEN_ROYALTIES: &str = "token_royalties";
+
+fn install_and_mint(builder: &mut InMemoryWasmTestBuilder) -> ContractHash {
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(10u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(builder);
+    let mint_session_call = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MINT_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => Key::from(nft_contract_hash),
+            ARG_TOKEN_OWNER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+        },
+    )
+    .build();
+    builder.exec(mint_session_call).expect_success().commit();
+    nft_contract_hash
+}
+
+fn get_last_event<
+    T: casper_types::bytesrepr::FromBytes + casper_types::CLTyped + core::fmt::Debug,
+>(
+    builder: &InMemoryWasmTestBuilder,
+    nft_contract_key: &Key,
+) -> T {
+    let events_length: u32 = support::query_stored_value(
+        builder,
+        *nft_contract_key,
+        vec![casper_event_standard::EVENTS_LENGTH.to_string()],
+    );
+    support::get_event(builder, nft_contract_key, events_length - 1)
+}
+
+#[test]
+fn should_set_default_and_token_royalties() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let nft_contract_hash = install_and_mint(&mut builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let creator = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    // Royalties go to the contract owner and are off until set.
+    let actual_recipient: Key = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![ROYALTY_RECIPIENT.to_string()],
+    );
+    assert_eq!(actual_recipient, creator);
+
+    let set_royalty_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_ROYALTY,
+        runtime_args! {
+            ARG_ROYALTY_RECIPIENT => creator,
+            ARG_ROYALTY_BASIS_POINTS => 500u32,
+        },
+    )
+    .build();
+    builder.exec(set_royalty_request).expect_success().commit();
+
+    let actual_basis_points: u32 = support::query_stored_value(
+        &builder,
+        nft_contract_key,
+        vec![ROYALTY_BASIS_POINTS.to_string()],
+    );
+    assert_eq!(actual_basis_points, 500u32);
+
+    let actual_event: RoyaltyUpdated = get_last_event(&builder, &nft_contract_key);
+    assert_eq!(actual_event, RoyaltyUpdated::new(None, creator, 500u32));
+
+    let token_royalty_recipient = Key::Account(AccountHash::new(ACCOUNT_USER_1));
+    let set_token_royalty_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_ROYALTY,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+            ARG_ROYALTY_RECIPIENT => token_royalty_recipient,
+            ARG_ROYALTY_BASIS_POINTS => 1_000u32,
+        },
+    )
+    .build();
+    builder
+        .exec(set_token_royalty_request)
+        .expect_success()
+        .commit();
+
+    let actual_token_royalty: Option<(Key, u32)> =
+        support::get_dictionary_value_from_key(&builder, &nft_contract_key, TOKEN_ROYALTIES, "1");
+    assert_eq!(
+        actual_token_royalty,
+        Some((token_royalty_recipient, 1_000u32))
+    );
+
+    let actual_event: RoyaltyUpdated = get_last_event(&builder, &nft_contract_key);
+    assert_eq!(
+        actual_event,
+        RoyaltyUpdated::new(Some("1".to_string()), token_royalty_recipient, 1_000u32)
+    );
+
+    let clear_token_royalty_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_CLEAR_TOKEN_ROYALTY,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+        },
+    )
+    .build();
+    builder
+        .exec(clear_token_royalty_request)
+        .expect_success()
+        .commit();
+
+    let actual_token_royalty: Option<(Key, u32)> =
+        support::get_dictionary_value_from_key(&builder, &nft_contract_key, TOKEN_ROYALTIES, "1");
+    assert_eq!(actual_token_royalty, None);
+
+    // The token falls back to the default royalty.
+    let actual_event: RoyaltyUpdated = get_last_event(&builder, &nft_contract_key);
+    assert_eq!(
+        actual_event,
+        RoyaltyUpdated::new(Some("1".to_string()), creator, 500u32)
+    );
+}
+
+fn get_royalty_info(
+    builder: &mut InMemoryWasmTestBuilder,
+    nft_contract_key: Key,
+    token_id: u64,
+    sale_price: U512,
+) -> (Key, U512) {
+    support::call_session_code_with_ret(
+        builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_key,
+        runtime_args! {
+            ARG_IS_HASH_IDENTIFIER_MODE => false,
+            ARG_TOKEN_ID => token_id,
+            ARG_SALE_PRICE => sale_price,
+        },
+        ROYALTY_INFO_SESSION_WASM,
+        ARG_KEY_NAME,
+    )
+}
+
+#[test]
+fn should_return_royalty_info_for_a_sale_price() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let nft_contract_hash = install_and_mint(&mut builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let creator = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let sale_price = U512::from(1_000u64);
+
+    let set_royalty_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_ROYALTY,
+        runtime_args! {
+            ARG_ROYALTY_RECIPIENT => creator,
+            ARG_ROYALTY_BASIS_POINTS => 500u32,
+        },
+    )
+    .build();
+    builder.exec(set_royalty_request).expect_success().commit();
+
+    let token_royalty_recipient = Key::Account(AccountHash::new(ACCOUNT_USER_1));
+    let set_token_royalty_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_TOKEN_ROYALTY,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+            ARG_ROYALTY_RECIPIENT => token_royalty_recipient,
+            ARG_ROYALTY_BASIS_POINTS => 1_000u32,
+        },
+    )
+    .build();
+    builder
+        .exec(set_token_royalty_request)
+        .expect_success()
+        .commit();
+
+    let actual_royalty_info = get_royalty_info(&mut builder, nft_contract_key, 1u64, sale_price);
+    assert_eq!(
+        actual_royalty_info,
+        (token_royalty_recipient, U512::from(100u64)),
+        "should pay the token royalty on the sale price"
+    );
+
+    let clear_token_royalty_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_CLEAR_TOKEN_ROYALTY,
+        runtime_args! {
+            ARG_TOKEN_ID => 1u64,
+        },
+    )
+    .build();
+    builder
+        .exec(clear_token_royalty_request)
+        .expect_success()
+        .commit();
+
+    let actual_royalty_info = get_royalty_info(&mut builder, nft_contract_key, 1u64, sale_price);
+    assert_eq!(
+        actual_royalty_info,
+        (creator, U512::from(50u64)),
+        "should fall back to the default royalty"
+    );
+}
+
+#[test]
+fn should_not_set_royalty_above_the_sale_price() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let nft_contract_hash = install_and_mint(&mut builder);
+
+    let set_royalty_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_SET_ROYALTY,
+        runtime_args! {
+            ARG_ROYALTY_RECIPIENT => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            ARG_ROYALTY_BASIS_POINTS => 10_001u32,
+        },
+    )
+    .build();
+    builder.exec(set_royalty_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        222u16,
+        "should not take more than the whole sale price",
+    );
+}
+
+#[test]
+fn should_only_allow_admin_to_set_royalty() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let nft_contract_hash = install_and_mint(&mut builder);
+    let account_user_1 = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+
+    let set_royalty_request = ExecuteRequestBuilder::contract_call_by_hash(
+        account_user_1,
+        nft_contract_hash,
+        ENTRY_POINT_SET_ROYALTY,
+        runtime_args! {
+            ARG_ROYALTY_RECIPIENT => Key::Account(account_user_1),
+            ARG_ROYALTY_BASIS_POINTS => 500u32,
+        },
+    )
+    .build();
+    builder.exec(set_royalty_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(error, 182u16, "should require the admin role");
+}
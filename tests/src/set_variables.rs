@@ -10,7 +10,8 @@ use contract::{
 
 use crate::utility::{
     constants::{
-        ACCOUNT_USER_1, CONTRACT_NAME, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, NFT_TEST_SYMBOL,
+        ACCOUNT_USER_1, CONTRACT_NAME, INSTALL_EVENTS_COUNT, NFT_CONTRACT_WASM,
+        NFT_TEST_COLLECTION, NFT_TEST_SYMBOL,
     },
     installer_request_builder::{InstallerRequestBuilder, OwnerReverseLookupMode},
     support,
@@ -79,6 +80,7 @@ fn only_installer_should_be_able_to_toggle_allow_minting() {
 
     // Expect VariablesSet event.
     let expected_event = VariablesSet::new();
-    let actual_event: VariablesSet = support::get_event(&builder, &nft_contract_key, 0);
+    let actual_event: VariablesSet =
+        support::get_event(&builder, &nft_contract_key, INSTALL_EVENTS_COUNT);
     assert_eq!(actual_event, expected_event, "Expected VariablesSet event.");
 }
@@ -2,17 +2,20 @@ use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, WasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
     DEFAULT_ACCOUNT_PUBLIC_KEY, DEFAULT_RUN_GENESIS_REQUEST, MINIMUM_ACCOUNT_CREATION_BALANCE,
 };
-use casper_execution_engine::storage::global_state::in_memory::InMemoryGlobalState;
+use casper_execution_engine::{
+    core::engine_state::ExecuteRequest, storage::global_state::in_memory::InMemoryGlobalState,
+};
 use casper_types::{
     account::AccountHash, runtime_args, ContractHash, Key, PublicKey, RuntimeArgs, SecretKey, U512,
 };
 use contract::{
     constants::{
         APPROVED, ARG_APPROVE_ALL, ARG_COLLECTION_NAME, ARG_CONTRACT_WHITELIST, ARG_OPERATOR,
-        ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_ID,
-        ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ENTRY_POINT_APPROVE, ENTRY_POINT_MINT,
-        ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_REVOKE, ENTRY_POINT_SET_APPROVALL_FOR_ALL,
-        ENTRY_POINT_TRANSFER, PAGE_TABLE, TOKEN_COUNT, TOKEN_OWNERS,
+        ARG_SOURCE_KEY, ARG_SPENDER, ARG_TARGET_KEY, ARG_TOKEN_ID, ARG_TOKEN_IDS,
+        ARG_TOKEN_META_DATA, ARG_TOKEN_OWNER, ENTRY_POINT_APPROVE, ENTRY_POINT_BATCH_TRANSFER,
+        ENTRY_POINT_MINT, ENTRY_POINT_REGISTER_OWNER, ENTRY_POINT_REVOKE,
+        ENTRY_POINT_SET_APPROVALL_FOR_ALL, ENTRY_POINT_TRANSFER, PAGE_TABLE, TOKEN_COUNT,
+        TOKEN_OWNERS,
     },
     events::events_ces::{Approval, ApprovalRevoked, Transfer},
     modalities::TokenIdentifier,
@@ -21,9 +24,9 @@ use contract::{
 use crate::utility::{
     constants::{
         ACCOUNT_USER_1, ACCOUNT_USER_2, ACCOUNT_USER_3, ARG_IS_HASH_IDENTIFIER_MODE,
-        ARG_NFT_CONTRACT_HASH, ARG_REVERSE_LOOKUP, CONTRACT_NAME, MINTING_CONTRACT_WASM,
-        MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION, NFT_TEST_SYMBOL,
-        TEST_PRETTY_721_META_DATA, TRANSFER_SESSION_WASM,
+        ARG_NFT_CONTRACT_HASH, ARG_REVERSE_LOOKUP, CONTRACT_NAME, INSTALL_EVENTS_COUNT,
+        MINTING_CONTRACT_WASM, MINT_SESSION_WASM, NFT_CONTRACT_WASM, NFT_TEST_COLLECTION,
+        NFT_TEST_SYMBOL, TEST_PRETTY_721_META_DATA, TRANSFER_SESSION_WASM,
     },
     installer_request_builder::{
         InstallerRequestBuilder, MetadataMutability, MintingMode, NFTHolderMode, NFTIdentifierMode,
@@ -254,7 +257,8 @@ fn should_transfer_token_from_sender_to_receiver() {
         token_receiver_key,
         TokenIdentifier::Index(1),
     );
-    let actual_event: Transfer = support::get_event(&builder, &nft_contract_key, 1);
+    let actual_event: Transfer =
+        support::get_event(&builder, &nft_contract_key, INSTALL_EVENTS_COUNT + 1);
     assert_eq!(actual_event, expected_event, "Expected Transfer event.");
 }
 
@@ -349,7 +353,7 @@ fn approve_token_for_transfer_should_add_entry_to_approved_dictionary(
 
     // Expect Approval event.
     let expected_event = Approval::new(owner_key, spender_key, TokenIdentifier::Index(token_id));
-    let expected_event_index = if operator.is_some() { 2 } else { 1 };
+    let expected_event_index = INSTALL_EVENTS_COUNT + if operator.is_some() { 2 } else { 1 };
     let actual_event: Approval =
         support::get_event(&builder, nft_contract_key, expected_event_index);
     assert_eq!(actual_event, expected_event, "Expected Approval event.");
@@ -481,7 +485,7 @@ fn revoke_token_for_transfer_should_remove_entry_to_approved_dictionary(
 
     // Expect ApprovalRevoked event.
     let expected_event = ApprovalRevoked::new(owner_key, TokenIdentifier::Index(token_id));
-    let expected_event_index = if operator.is_some() { 3 } else { 2 };
+    let expected_event_index = INSTALL_EVENTS_COUNT + if operator.is_some() { 3 } else { 2 };
     let actual_event: ApprovalRevoked =
         support::get_event(&builder, nft_contract_key, expected_event_index);
     assert_eq!(
@@ -1830,3 +1834,262 @@ fn disallow_owner_to_approve_for_all_itself() {
         "should not allow an owner to approve_for_all itself",
     );
 }
+
+fn transfer_token_request(
+    nft_contract_key: Key,
+    token_id: u64,
+    source_key: Key,
+    target_key: Key,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        TRANSFER_SESSION_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_HASH => nft_contract_key,
+            ARG_TOKEN_ID => token_id,
+            ARG_IS_HASH_IDENTIFIER_MODE => false,
+            ARG_SOURCE_KEY => source_key,
+            ARG_TARGET_KEY => target_key,
+        },
+    )
+    .build()
+}
+
+#[test]
+fn should_only_transfer_soulbound_tokens_once_enabled_or_exempt() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(2u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_enable_transfer(false)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    for _ in 0..2 {
+        let mint_session_call = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            MINT_SESSION_WASM,
+            runtime_args! {
+                ARG_NFT_CONTRACT_HASH => nft_contract_key,
+                ARG_TOKEN_OWNER => token_owner_key,
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+                ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+            },
+        )
+        .build();
+        builder.exec(mint_session_call).expect_success().commit();
+    }
+
+    let token_receiver_key = Key::Account(create_funded_dummy_account(
+        &mut builder,
+        Some(ACCOUNT_USER_1),
+    ));
+
+    let register_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REGISTER_OWNER,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_receiver_key
+        },
+    )
+    .build();
+    builder.exec(register_request).expect_success().commit();
+
+    builder
+        .exec(transfer_token_request(
+            nft_contract_key,
+            1u64,
+            token_owner_key,
+            token_receiver_key,
+        ))
+        .expect_failure();
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        183u16,
+        "should disallow transfer of soulbound tokens",
+    );
+
+    let exemption_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        "set_transfer_exemption",
+        runtime_args! {
+            "account" => token_owner_key,
+            "exempt" => true,
+        },
+    )
+    .build();
+    builder.exec(exemption_request).expect_success().commit();
+
+    builder
+        .exec(transfer_token_request(
+            nft_contract_key,
+            1u64,
+            token_owner_key,
+            token_receiver_key,
+        ))
+        .expect_success()
+        .commit();
+
+    let exemption_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        "set_transfer_exemption",
+        runtime_args! {
+            "account" => token_owner_key,
+            "exempt" => false,
+        },
+    )
+    .build();
+    builder.exec(exemption_request).expect_success().commit();
+
+    let enable_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        "set_transfer_enabled",
+        runtime_args! {
+            "enable_transfer" => true,
+        },
+    )
+    .build();
+    builder
+        .exec(enable_transfer_request)
+        .expect_success()
+        .commit();
+
+    builder
+        .exec(transfer_token_request(
+            nft_contract_key,
+            2u64,
+            token_owner_key,
+            token_receiver_key,
+        ))
+        .expect_success()
+        .commit();
+}
+
+#[test]
+fn should_batch_transfer_tokens_and_update_balances_once() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, NFT_CONTRACT_WASM)
+        .with_total_token_supply(3u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let nft_contract_hash = get_nft_contract_hash(&builder);
+    let nft_contract_key: Key = nft_contract_hash.into();
+    let token_owner_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    for _ in 0..3 {
+        let mint_session_call = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            MINT_SESSION_WASM,
+            runtime_args! {
+                ARG_NFT_CONTRACT_HASH => nft_contract_key,
+                ARG_TOKEN_OWNER => token_owner_key,
+                ARG_TOKEN_META_DATA => TEST_PRETTY_721_META_DATA.to_string(),
+                ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string()
+            },
+        )
+        .build();
+        builder.exec(mint_session_call).expect_success().commit();
+    }
+
+    let token_receiver = create_funded_dummy_account(&mut builder, Some(ACCOUNT_USER_1));
+    let token_receiver_key = Key::Account(token_receiver);
+
+    let register_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_REGISTER_OWNER,
+        runtime_args! {
+            ARG_TOKEN_OWNER => token_receiver_key
+        },
+    )
+    .build();
+    builder.exec(register_request).expect_success().commit();
+
+    let batch_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BATCH_TRANSFER,
+        runtime_args! {
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => token_receiver_key,
+            ARG_TOKEN_IDS => vec![1u64, 3u64],
+        },
+    )
+    .build();
+    builder
+        .exec(batch_transfer_request)
+        .expect_success()
+        .commit();
+
+    for token_id in [1u64, 3u64] {
+        let actual_token_owner: Key = get_dictionary_value_from_key(
+            &builder,
+            &nft_contract_key,
+            TOKEN_OWNERS,
+            &token_id.to_string(),
+        );
+        assert_eq!(actual_token_owner, token_receiver_key);
+    }
+
+    let actual_sender_balance: u64 = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        TOKEN_COUNT,
+        &DEFAULT_ACCOUNT_ADDR.to_string(),
+    );
+    assert_eq!(actual_sender_balance, 1u64);
+
+    let actual_receiver_balance: u64 = get_dictionary_value_from_key(
+        &builder,
+        &nft_contract_key,
+        TOKEN_COUNT,
+        &token_receiver.to_string(),
+    );
+    assert_eq!(actual_receiver_balance, 2u64);
+
+    let token_receiver_page =
+        support::get_token_page_by_id(&builder, &nft_contract_key, &token_receiver_key, 3u64);
+    assert!(token_receiver_page[1] && token_receiver_page[3]);
+
+    // Token 1 is no longer owned by the source, so the whole batch is rejected.
+    let batch_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        nft_contract_hash,
+        ENTRY_POINT_BATCH_TRANSFER,
+        runtime_args! {
+            ARG_SOURCE_KEY => token_owner_key,
+            ARG_TARGET_KEY => token_receiver_key,
+            ARG_TOKEN_IDS => vec![2u64, 1u64],
+        },
+    )
+    .build();
+    builder.exec(batch_transfer_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        1u16,
+        "should not transfer a token the source does not own",
+    );
+
+    let actual_token_owner: Key =
+        get_dictionary_value_from_key(&builder, &nft_contract_key, TOKEN_OWNERS, "2");
+    assert_eq!(actual_token_owner, token_owner_key);
+}
@@ -29,6 +29,7 @@ use crate::utility::{
 const OWNED_TOKENS: &str = "owned_tokens";
 const MANGLED_ACCESS_KEY_NAME: &str = "mangled_access_key";
 const MANGLED_HASH_KEY_NAME: &str = "mangled_hash_key";
+const ARG_NUMBER_OF_BURNT_TOKENS: &str = "number_of_burnt_tokens";
 
 #[test]
 fn should_not_be_able_to_reinvoke_migrate_entrypoint() {
@@ -278,3 +279,42 @@ fn should_not_upgrade_with_larger_total_token_supply() {
         "cannot upgrade when new total token supply is larger than pre-migration one",
     );
 }
+
+#[test]
+fn should_not_upgrade_with_more_burnt_tokens_than_minted() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST).commit();
+
+    let install_request = InstallerRequestBuilder::new(*DEFAULT_ACCOUNT_ADDR, CONTRACT_1_0_0_WASM)
+        .with_collection_name(NFT_TEST_COLLECTION.to_string())
+        .with_collection_symbol(NFT_TEST_SYMBOL.to_string())
+        .with_total_token_supply(100u64)
+        .with_ownership_mode(OwnershipMode::Transferable)
+        .with_identifier_mode(NFTIdentifierMode::Ordinal)
+        .with_nft_metadata_kind(NFTMetadataKind::Raw)
+        .build();
+
+    builder.exec(install_request).expect_success().commit();
+
+    let upgrade_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        NFT_CONTRACT_WASM,
+        runtime_args! {
+            ARG_NFT_CONTRACT_PACKAGE_HASH => support::get_nft_contract_package_hash(&builder),
+            ARG_COLLECTION_NAME => NFT_TEST_COLLECTION.to_string(),
+            ARG_NAMED_KEY_CONVENTION => NamedKeyConventionMode::V1_0Standard as u8,
+            ARG_ACCESS_KEY_NAME_1_0_0 => ACCESS_KEY_NAME_1_0_0.to_string(),
+            ARG_NUMBER_OF_BURNT_TOKENS => 1u64
+        },
+    )
+    .build();
+
+    builder.exec(upgrade_request).expect_failure();
+    let error = builder.get_error().expect("must have error");
+
+    support::assert_expected_error(
+        error,
+        214u16,
+        "cannot upgrade with more burnt tokens than minted tokens",
+    );
+}
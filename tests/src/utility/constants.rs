@@ -9,6 +9,9 @@ pub const MINT_SESSION_WASM: &str = "mint_call.wasm";
 pub const MINTING_CONTRACT_WASM: &str = "minting_contract.wasm";
 pub const NFT_CONTRACT_WASM: &str = "contract.wasm";
 pub const OWNER_OF_SESSION_WASM: &str = "owner_of_call.wasm";
+pub const SUPPLY_SESSION_WASM: &str = "supply_call.wasm";
+pub const ROYALTY_INFO_SESSION_WASM: &str = "royalty_info_call.wasm";
+pub const TOKENS_OF_OWNER_SESSION_WASM: &str = "tokens_of_owner_call.wasm";
 pub const TRANSFER_SESSION_WASM: &str = "transfer_call.wasm";
 
 pub const ARG_IS_HASH_IDENTIFIER_MODE: &str = "is_hash_identifier_mode";
@@ -28,6 +31,9 @@ pub const ACCOUNT_USER_3: [u8; 32] = [3u8; 32];
 
 pub const PAGE_SIZE: u64 = 1000;
 
+// The RoleGranted events of the installer getting every role.
+pub const INSTALL_EVENTS_COUNT: u32 = 4;
+
 pub const TEST_PRETTY_721_META_DATA: &str = r#"{
   "name": "John Doe",
   "symbol": "abc",
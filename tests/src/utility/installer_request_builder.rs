@@ -147,6 +147,11 @@ pub(crate) struct InstallerRequestBuilder {
     events_mode: CLValue,
     the_contract_minter: CLValue,
     the_contract_owner: CLValue,
+    enable_transfer: CLValue,
+    team_reserve: CLValue,
+    redeem_reserve: CLValue,
+    metadata_seed_hash: Option<String>,
+    reveal_base_uri_hash: Option<String>,
 }
 
 impl InstallerRequestBuilder {
@@ -190,6 +195,11 @@ impl InstallerRequestBuilder {
             events_mode: CLValue::from_t(EventsMode::CES as u8).unwrap(),
             the_contract_minter: CLValue::from_t(Key::from(AccountHash::default())).unwrap(),
             the_contract_owner: CLValue::from_t(Key::from(AccountHash::default())).unwrap(),
+            enable_transfer: CLValue::from_t(true).unwrap(),
+            team_reserve: CLValue::from_t(0u64).unwrap(),
+            redeem_reserve: CLValue::from_t(0u64).unwrap(),
+            metadata_seed_hash: None,
+            reveal_base_uri_hash: None,
         }
     }
 
@@ -208,6 +218,31 @@ impl InstallerRequestBuilder {
         self
     }
 
+    pub(crate) fn with_enable_transfer(mut self, enable_transfer: bool) -> Self {
+        self.enable_transfer = CLValue::from_t(enable_transfer).unwrap();
+        self
+    }
+
+    pub(crate) fn with_team_reserve(mut self, team_reserve: u64) -> Self {
+        self.team_reserve = CLValue::from_t(team_reserve).unwrap();
+        self
+    }
+
+    pub(crate) fn with_redeem_reserve(mut self, redeem_reserve: u64) -> Self {
+        self.redeem_reserve = CLValue::from_t(redeem_reserve).unwrap();
+        self
+    }
+
+    pub(crate) fn with_metadata_seed_hash(mut self, metadata_seed_hash: String) -> Self {
+        self.metadata_seed_hash = Some(metadata_seed_hash);
+        self
+    }
+
+    pub(crate) fn with_reveal_base_uri_hash(mut self, reveal_base_uri_hash: String) -> Self {
+        self.reveal_base_uri_hash = Some(reveal_base_uri_hash);
+        self
+    }
+
     pub(crate) fn with_session_file(mut self, session_file: String) -> Self {
         self.session_file = session_file;
         self
@@ -353,6 +388,19 @@ impl InstallerRequestBuilder {
         runtime_args.insert_cl_value(ARG_EVENTS_MODE, self.events_mode);
         runtime_args.insert_cl_value("the_contract_minter", self.the_contract_minter);
         runtime_args.insert_cl_value("the_contract_owner", self.the_contract_owner);
+        runtime_args.insert_cl_value("enable_transfer", self.enable_transfer);
+        runtime_args.insert_cl_value("team_reserve", self.team_reserve);
+        runtime_args.insert_cl_value("redeem_reserve", self.redeem_reserve);
+        if let Some(metadata_seed_hash) = self.metadata_seed_hash {
+            runtime_args
+                .insert("metadata_seed_hash", metadata_seed_hash)
+                .unwrap();
+        }
+        if let Some(reveal_base_uri_hash) = self.reveal_base_uri_hash {
+            runtime_args
+                .insert("reveal_base_uri_hash", reveal_base_uri_hash)
+                .unwrap();
+        }
         runtime_args.insert_cl_value(
             ARG_ADDITIONAL_REQUIRED_METADATA,
             self.additional_required_metadata,